@@ -0,0 +1,86 @@
+//! Per-keystroke latency of ranking 100k autocomplete suggestions.
+//!
+//! Run with `cargo bench --bench matcher`. A keystroke should take well
+//! under 16ms (a frame at 60 FPS). The `typing` benchmarks measure a whole
+//! query typed keystroke by keystroke, see their throughput per keystroke.
+
+use cliclack::matcher::{JaroWinkler, Matched, Matcher, Prefix, Subsequence, Substring};
+use cliclack::Suggest;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const ITEMS: usize = 100_000;
+
+/// The number of suggestions visible in the popup.
+const LIMIT: usize = 10;
+
+/// The number of items ranked by the filter mode of selection prompts:
+/// the visible rows and the headroom for scrolling.
+const FILTER_LIMIT: usize = LIMIT + 64;
+
+/// Generates package-like names, e.g. `async-serde-core-4211`.
+fn labels() -> Vec<String> {
+    const WORDS: [&str; 16] = [
+        "async", "serde", "core", "http", "json", "tokio", "derive", "macro", "cli", "log", "test",
+        "utils", "parser", "proto", "sys", "web",
+    ];
+
+    // A deterministic linear congruential generator.
+    let mut seed: u64 = 42;
+    let mut next = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    };
+
+    (0..ITEMS)
+        .map(|_| {
+            let words: Vec<&str> = (0..1 + next() % 3).map(|_| WORDS[next() % 16]).collect();
+            format!("{}-{}", words.join("-"), next() % 10_000)
+        })
+        .collect()
+}
+
+fn typing<M: Matcher>(c: &mut Criterion, name: &str, matcher: M, limit: usize) {
+    let matched = Matched::new(labels(), matcher).limit(limit);
+    // Index the labels in advance, as on the prompt start.
+    matched.suggest("");
+
+    let mut group = c.benchmark_group(name);
+
+    // Starting a new query from scratch.
+    let mut flip = false;
+    group.bench_function("first keystroke", |b| {
+        b.iter(|| {
+            flip = !flip;
+            matched.suggest(if flip { "s" } else { "t" })
+        })
+    });
+
+    // Typing a query keystroke by keystroke.
+    let queries = ["s", "se", "ser", "serd", "serde", "serde-", "serde-c"];
+    group.throughput(Throughput::Elements(queries.len() as u64));
+    group.bench_function("typing", |b| {
+        b.iter(|| {
+            for query in queries {
+                matched.suggest(query);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn matchers(c: &mut Criterion) {
+    typing(c, "subsequence", Subsequence::new(), LIMIT);
+    typing(c, "substring", Substring::new(), LIMIT);
+    typing(c, "prefix", Prefix::new(), LIMIT);
+    typing(c, "jaro-winkler", JaroWinkler::new(), LIMIT);
+
+    // Ranking a page of items, like the filter mode of selection prompts does.
+    typing(c, "jaro-winkler filter", JaroWinkler::new(), FILTER_LIMIT);
+    typing(c, "subsequence filter", Subsequence::new(), FILTER_LIMIT);
+}
+
+criterion_group!(benches, matchers);
+criterion_main!(benches);
@@ -0,0 +1,472 @@
+//! `#[derive(Prompt)]` macro for the [`cliclack`](https://docs.rs/cliclack) crate.
+//!
+//! Not intended to be used directly: enable the `derive` feature of `cliclack`
+//! instead, see `cliclack::Prompt` for the documentation.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, Ident, Lit, LitStr, PathArguments,
+    Token, Type,
+};
+
+/// Generates an implementation of the `cliclack::Prompt` trait.
+///
+/// * For a struct, every field is prompted in order of declaration.
+/// * For an enum with unit variants, a selection prompt is generated, and
+///   the enum can be used as a field type of another struct.
+#[proc_macro_derive(Prompt, attributes(prompt))]
+pub fn derive_prompt(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let result = match &input.data {
+        Data::Struct(data) => derive_struct(&input, &data.fields),
+        Data::Enum(data) => derive_enum(&input, data.variants.iter()),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Prompt` can't be derived for unions",
+        )),
+    };
+
+    result.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Options of the `#[prompt(...)]` attribute.
+///
+/// ```text
+/// #[prompt("Prompt text", placeholder = "...", default = ..., validate = ...,
+///          choices = ["a", "b"], hint = "...", password, skip)]
+/// ```
+#[derive(Default)]
+struct Attrs {
+    text: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    default: Option<Lit>,
+    validate: Option<Expr>,
+    choices: Option<Vec<LitStr>>,
+    hint: Option<LitStr>,
+    password: bool,
+    skip: bool,
+    /// Names of the given options, for reporting the unsupported ones.
+    options: Vec<Ident>,
+}
+
+impl Parse for Attrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = Attrs::default();
+
+        if input.peek(LitStr) {
+            attrs.text = Some(input.parse()?);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            attrs.options.push(ident.clone());
+
+            match ident.to_string().as_str() {
+                "password" => attrs.password = true,
+                "skip" => attrs.skip = true,
+                "placeholder" => {
+                    input.parse::<Token![=]>()?;
+                    attrs.placeholder = Some(input.parse()?);
+                }
+                "hint" => {
+                    input.parse::<Token![=]>()?;
+                    attrs.hint = Some(input.parse()?);
+                }
+                "default" => {
+                    input.parse::<Token![=]>()?;
+                    attrs.default = Some(input.parse()?);
+                }
+                "validate" => {
+                    input.parse::<Token![=]>()?;
+                    attrs.validate = Some(input.parse()?);
+                }
+                "choices" => {
+                    input.parse::<Token![=]>()?;
+                    let content;
+                    bracketed!(content in input);
+                    let choices = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    attrs.choices = Some(choices.into_iter().collect());
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown `prompt` option: `{ident}`"),
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+impl Attrs {
+    /// Collects options from all `#[prompt(...)]` attributes of an item.
+    fn from_attributes(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut attrs = Attrs::default();
+
+        for attr in attributes.iter().filter(|a| a.path().is_ident("prompt")) {
+            let mut next: Attrs = attr.parse_args()?;
+            next.options.splice(0..0, attrs.options);
+            attrs = Attrs {
+                text: next.text.or(attrs.text),
+                placeholder: next.placeholder.or(attrs.placeholder),
+                default: next.default.or(attrs.default),
+                validate: next.validate.or(attrs.validate),
+                choices: next.choices.or(attrs.choices),
+                hint: next.hint.or(attrs.hint),
+                password: next.password || attrs.password,
+                skip: next.skip || attrs.skip,
+                options: next.options,
+            };
+        }
+
+        Ok(attrs)
+    }
+
+    /// The prompt text, or a fallback one built from the item name.
+    fn text(&self, fallback: impl FnOnce() -> String) -> LitStr {
+        self.text
+            .clone()
+            .unwrap_or_else(|| LitStr::new(&fallback(), Span::call_site()))
+    }
+
+    /// Fails on the first of the given options, if any, as not supported by
+    /// the prompt generated for the field.
+    fn reject(&self, unsupported: &[&str], prompt: &str) -> syn::Result<()> {
+        match self
+            .options
+            .iter()
+            .find(|o| unsupported.iter().any(|u| o == u))
+        {
+            Some(option) => Err(syn::Error::new(
+                option.span(),
+                format!("`{option}` is not supported for {prompt}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The default value expected to be a string literal.
+    fn default_str(&self) -> syn::Result<Option<&LitStr>> {
+        match &self.default {
+            None => Ok(None),
+            Some(Lit::Str(lit)) => Ok(Some(lit)),
+            Some(lit) => Err(syn::Error::new_spanned(
+                lit,
+                "expected a string literal, e.g. `default = \"42\"`",
+            )),
+        }
+    }
+}
+
+/// Turns `project_name` into "Project name".
+fn humanize(ident: &Ident) -> String {
+    let name = ident.to_string().replace('_', " ");
+    let name = name.trim_start_matches("r#");
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns `T` if the type looks like `Wrapper<T>` (e.g. `Option<T>` or `Vec<T>`).
+fn generic_arg<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) if args.args.len() == 1 => Some(ty),
+        _ => None,
+    }
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
+
+fn derive_struct(input: &DeriveInput, fields: &Fields) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match fields {
+        Fields::Named(named) => {
+            let fields = named
+                .named
+                .iter()
+                .map(|field| {
+                    let name = field.ident.as_ref().unwrap();
+                    let value = field_prompt(name, &field.ty, &field.attrs)?;
+                    Ok(quote!(#name: #value))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!(Self { #(#fields),* })
+        }
+        Fields::Unnamed(unnamed) => {
+            let fields = unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let name = Ident::new(&format!("field_{i}"), Span::call_site());
+                    field_prompt(&name, &field.ty, &field.attrs)
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!(Self(#(#fields),*))
+        }
+        Fields::Unit => quote!(Self),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::cliclack::Prompt for #ident #ty_generics #where_clause {
+            fn interact() -> ::std::io::Result<Self> {
+                ::std::result::Result::Ok(#body)
+            }
+        }
+    })
+}
+
+/// Generates an expression prompting for a value of a single field.
+fn field_prompt(name: &Ident, ty: &Type, attributes: &[Attribute]) -> syn::Result<TokenStream> {
+    let attrs = Attrs::from_attributes(attributes)?;
+
+    if attrs.skip {
+        return Ok(quote!(::std::default::Default::default()));
+    }
+
+    let text = attrs.text(|| humanize(name));
+    let private = quote!(::cliclack::__private);
+
+    let placeholder = attrs.placeholder.as_ref().map(|p| quote!(.placeholder(#p)));
+    let validate = attrs.validate.as_ref().map(|v| quote!(.validate(#v)));
+
+    // `bool` -> `Confirm`.
+    if is_bool(ty) {
+        attrs.reject(
+            &["placeholder", "validate", "choices", "hint", "password"],
+            "a `bool` field",
+        )?;
+        let initial_value = match &attrs.default {
+            None => None,
+            Some(Lit::Bool(lit)) => Some(quote!(.initial_value(#lit))),
+            Some(lit) => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected a boolean, e.g. `default = true`",
+                ))
+            }
+        };
+        return Ok(quote! {
+            ::cliclack::Confirm::new(#text) #initial_value .interact()?
+        });
+    }
+
+    let default = attrs.default_str()?;
+
+    // `password` -> `Password`.
+    if attrs.password {
+        attrs.reject(
+            &["placeholder", "default", "choices", "hint"],
+            "a `password` field",
+        )?;
+        return Ok(quote! {
+            #private::parse::<#ty>(
+                &::cliclack::Password::new(#text) #validate .interact()?
+            )?
+        });
+    }
+
+    // `choices = [...]` -> `Select`/`MultiSelect` over the listed strings.
+    if let Some(choices) = &attrs.choices {
+        let items = choices.iter().map(|c| quote!((#c, #c, "")));
+
+        if let Some(inner) = generic_arg(ty, "Vec") {
+            attrs.reject(
+                &["placeholder", "default", "validate", "hint"],
+                "a `Vec` field",
+            )?;
+            return Ok(quote! {
+                ::cliclack::MultiSelect::new(#text)
+                    .items(&[#(#items),*])
+                    .interact()?
+                    .into_iter()
+                    .map(#private::parse::<#inner>)
+                    .collect::<::std::io::Result<_>>()?
+            });
+        }
+
+        attrs.reject(&["placeholder", "validate", "hint"], "a `choices` field")?;
+        if let Some(default) = default {
+            if !choices.iter().any(|c| c.value() == default.value()) {
+                let choices = choices.iter().map(|c| format!("{:?}", c.value()));
+                return Err(syn::Error::new_spanned(
+                    default,
+                    format!(
+                        "expected one of the choices: {}",
+                        choices.collect::<Vec<_>>().join(", ")
+                    ),
+                ));
+            }
+        }
+        let initial_value = default.map(|d| quote!(.initial_value(#d)));
+        return Ok(quote! {
+            #private::parse::<#ty>(
+                ::cliclack::Select::new(#text)
+                    #initial_value
+                    .items(&[#(#items),*])
+                    .interact()?
+            )?
+        });
+    }
+
+    // `Vec<T>` -> `MultiSelect` over the choices of `T`.
+    if let Some(inner) = generic_arg(ty, "Vec") {
+        attrs.reject(
+            &["placeholder", "default", "validate", "hint"],
+            "a `Vec` field",
+        )?;
+        return Ok(quote! {
+            ::cliclack::MultiSelect::new(#text)
+                .items(&<#inner as ::cliclack::Choices>::choices())
+                .interact()?
+        });
+    }
+
+    attrs.reject(&["hint"], "a field (only enum variants have hints)")?;
+    let default_input = default.map(|d| quote!(.default_input(#d)));
+
+    // `Option<T>` -> not required `Input`, empty input is `None`.
+    if let Some(inner) = generic_arg(ty, "Option") {
+        let validate = attrs.validate.as_ref().map(|v| {
+            quote! {
+                <_ as ::cliclack::Validate<::std::string::String>>::validate(&#v, value)
+                    .map_err(|err| err.to_string())?;
+            }
+        });
+        return Ok(quote! {{
+            let value: ::std::string::String = ::cliclack::Input::new(#text)
+                #placeholder
+                #default_input
+                .required(false)
+                .validate(|value: &::std::string::String| {
+                    if value.is_empty() {
+                        return ::std::result::Result::Ok(());
+                    }
+                    #validate
+                    #private::parse::<#inner>(value)
+                        .map(|_| ())
+                        .map_err(|err| err.to_string())
+                })
+                .interact()?;
+            if value.is_empty() {
+                ::std::option::Option::None
+            } else {
+                ::std::option::Option::Some(#private::parse::<#inner>(&value)?)
+            }
+        }});
+    }
+
+    // Otherwise, `Select` for `Choices` (derived enums) or `Input` for `FromStr`.
+    let select_default = match default {
+        Some(d) => quote!(::std::option::Option::Some(#d)),
+        None => quote!(::std::option::Option::None),
+    };
+    Ok(quote! {{
+        #[allow(unused_imports)]
+        use #private::{InputField as _, SelectField as _};
+        (&&#private::Field::<#ty>::new(#text, #select_default)).interact(
+            &|input: ::cliclack::Input| input #placeholder #default_input #validate
+        )?
+    }})
+}
+
+fn derive_enum<'a>(
+    input: &DeriveInput,
+    variants: impl Iterator<Item = &'a syn::Variant>,
+) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let attrs = Attrs::from_attributes(&input.attrs)?;
+    attrs.reject(
+        &[
+            "placeholder",
+            "default",
+            "validate",
+            "choices",
+            "hint",
+            "password",
+            "skip",
+        ],
+        "an enum",
+    )?;
+    let text = attrs.text(|| humanize(ident));
+
+    let items = variants
+        .map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "`Prompt` can only be derived for enums with unit variants",
+                ));
+            }
+            let attrs = Attrs::from_attributes(&variant.attrs)?;
+            attrs.reject(
+                &[
+                    "placeholder",
+                    "default",
+                    "validate",
+                    "choices",
+                    "password",
+                    "skip",
+                ],
+                "an enum variant",
+            )?;
+            let name = &variant.ident;
+            let label = attrs.text(|| name.to_string());
+            let hint = attrs
+                .hint
+                .map(ToTokens::into_token_stream)
+                .unwrap_or_else(|| quote!(""));
+            Ok(quote! {
+                (Self::#name, #label.to_string(), #hint.to_string())
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::cliclack::Choices for #ident #ty_generics #where_clause {
+            fn choices() -> ::std::vec::Vec<(Self, ::std::string::String, ::std::string::String)> {
+                ::std::vec![#(#items),*]
+            }
+        }
+
+        impl #impl_generics ::cliclack::Prompt for #ident #ty_generics #where_clause {
+            fn interact() -> ::std::io::Result<Self> {
+                ::cliclack::Select::new(#text)
+                    .items(&<Self as ::cliclack::Choices>::choices())
+                    .interact()
+            }
+        }
+    })
+}
@@ -0,0 +1,15 @@
+use cliclack::Prompt;
+
+#[derive(Prompt)]
+struct Confirm {
+    #[prompt("Install?", default = "yes")]
+    install: bool,
+}
+
+#[derive(Prompt)]
+struct Input {
+    #[prompt("Port", default = 8080)]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use cliclack::Prompt;
+
+#[derive(Prompt)]
+struct Config {
+    #[prompt("Language", choices = ["rust", "go"], default = "ruby")]
+    language: String,
+}
+
+fn main() {}
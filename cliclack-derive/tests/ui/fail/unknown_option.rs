@@ -0,0 +1,9 @@
+use cliclack::Prompt;
+
+#[derive(Prompt)]
+struct Config {
+    #[prompt("Name", placehodler = "my-app")]
+    name: String,
+}
+
+fn main() {}
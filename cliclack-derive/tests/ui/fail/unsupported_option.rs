@@ -0,0 +1,39 @@
+use cliclack::Prompt;
+
+#[derive(Prompt, Clone, PartialEq, Eq)]
+enum Tool {
+    #[prompt("Prettier", default = "yes")]
+    Prettier,
+}
+
+#[derive(Prompt)]
+struct Vector {
+    #[prompt("Tools", default = "Prettier")]
+    tools: Vec<Tool>,
+}
+
+#[derive(Prompt)]
+struct Choices {
+    #[prompt("License", choices = ["MIT", "GPL"], placeholder = "MIT")]
+    license: String,
+}
+
+#[derive(Prompt)]
+struct Confirm {
+    #[prompt("Install?", validate = |_: &String| Ok::<(), String>(()))]
+    install: bool,
+}
+
+#[derive(Prompt)]
+struct Password {
+    #[prompt("Token", password, default = "secret")]
+    token: String,
+}
+
+#[derive(Prompt)]
+struct Input {
+    #[prompt("Name", hint = "your name")]
+    name: String,
+}
+
+fn main() {}
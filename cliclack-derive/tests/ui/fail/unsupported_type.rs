@@ -0,0 +1,14 @@
+use cliclack::Prompt;
+
+#[derive(Prompt, Clone, PartialEq, Eq)]
+enum Shape {
+    Circle(u32),
+}
+
+#[derive(Prompt)]
+union Number {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,93 @@
+use std::io::ErrorKind;
+use std::net::IpAddr;
+
+use cliclack::Prompt;
+
+#[derive(Prompt, Clone, PartialEq, Eq, Debug)]
+#[prompt("Pick a language")]
+enum Language {
+    #[prompt("Rust", hint = "recommended")]
+    Rust,
+    #[prompt(hint = "the label is the variant name")]
+    TypeScript,
+    Go,
+}
+
+#[derive(Prompt)]
+struct Config {
+    #[prompt("Project name", placeholder = "my-app")]
+    name: String,
+    #[prompt(
+        "Port",
+        default = "8080",
+        validate = |input: &String| match input.starts_with('0') {
+            true => Err("No leading zeros"),
+            false => Ok(()),
+        }
+    )]
+    port: u16,
+    // The text is made from the field name.
+    address: IpAddr,
+    #[prompt("Language", default = "Rust")]
+    language: Language,
+    #[prompt("Other languages")]
+    languages: Vec<Language>,
+    #[prompt("Install?", default = true)]
+    install: bool,
+    #[prompt("Description", placeholder = "optional", default = "none")]
+    description: Option<String>,
+    #[prompt("Retries")]
+    #[prompt(validate = |input: &String| match input.len() < 3 {
+        true => Ok(()),
+        false => Err("Too many"),
+    })]
+    retries: Option<u8>,
+    #[prompt("License", choices = ["MIT", "Apache-2.0"], default = "MIT")]
+    license: String,
+    #[prompt("Licenses", choices = ["MIT", "Apache-2.0"])]
+    licenses: Vec<String>,
+    #[prompt("Token", password, validate = |input: &String| match input.is_empty() {
+        true => Err("Required"),
+        false => Ok(()),
+    })]
+    token: String,
+    #[prompt(skip)]
+    cache: Vec<String>,
+}
+
+#[derive(Prompt)]
+struct Tuple(#[prompt("Name")] String, bool);
+
+#[derive(Prompt)]
+struct Unit;
+
+fn main() {
+    // Not a terminal: the first prompt fails, the rest aren't started.
+    for result in [
+        Config::interact().map(|_| ()),
+        Tuple::interact().map(|_| ()),
+        Language::interact().map(|_| ()),
+    ] {
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotConnected);
+    }
+
+    // Nothing to prompt.
+    assert!(Unit::interact().is_ok());
+
+    let labels: Vec<_> = <Language as cliclack::Choices>::choices()
+        .into_iter()
+        .map(|(value, label, hint)| (value, label, hint))
+        .collect();
+    assert_eq!(
+        labels,
+        vec![
+            (Language::Rust, "Rust".into(), "recommended".into()),
+            (
+                Language::TypeScript,
+                "TypeScript".into(),
+                "the label is the variant name".into()
+            ),
+            (Language::Go, "Go".into(), "".into()),
+        ]
+    );
+}
@@ -1,3 +1,4 @@
+use cliclack::Suggestion;
 use console::style;
 use std::io;
 
@@ -24,6 +25,8 @@ fn main() -> io::Result<()> {
     let language: String = cliclack::input("Pick a language")
         .default_input("rust")
         .autocomplete(languages)
+        .autocomplete_max_rows(5)
+        .autocomplete_strict()
         .interact()?;
 
     let food: String = cliclack::input("What's your favorite food?")
@@ -37,7 +40,45 @@ fn main() -> io::Result<()> {
         })
         .interact()?;
 
-    cliclack::outro(format!("Selected: {language} and {food}"))?;
+    let command: String = cliclack::input("Git command")
+        .placeholder("Right or End accepts the suggestion, Alt-f accepts a word")
+        .autocomplete(vec![
+            "git status".to_string(),
+            "git stash pop".to_string(),
+            "git commit --amend".to_string(),
+            "git push --force-with-lease".to_string(),
+        ])
+        .ghost_text()
+        .autocomplete_popup(false)
+        .interact()?;
+
+    let branch: String = cliclack::input("Branch")
+        .placeholder("Tab completes like in a shell, Shift-Tab cycles back")
+        .autocomplete(vec![
+            "feature/login".to_string(),
+            "feature/logout".to_string(),
+            "fix/typo".to_string(),
+            "main".to_string(),
+        ])
+        .autocomplete_shell_style()
+        .interact()?;
+
+    let port: u16 = cliclack::input("Port")
+        .placeholder("Type a service name or a number")
+        .autocomplete_items(vec![
+            Suggestion::new("http").hint("80").insert(80).value(80),
+            Suggestion::new("https").hint("443").insert(443).value(443),
+            Suggestion::new("ssh").hint("22").insert(22).value(22),
+            Suggestion::new("postgres")
+                .hint("5432")
+                .insert(5432)
+                .value(5432),
+        ])
+        .interact()?;
+
+    cliclack::outro(format!(
+        "Selected: {language} and {food}, ran {command} on {branch}, port {port}"
+    ))?;
 
     Ok(())
 }
@@ -0,0 +1,46 @@
+use std::thread;
+use std::time::Duration;
+
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    cliclack::intro(style(" async autocomplete ").on_cyan().black())?;
+
+    let crates = [
+        "serde",
+        "serde_json",
+        "tokio",
+        "clap",
+        "rand",
+        "regex",
+        "anyhow",
+        "thiserror",
+        "chrono",
+        "reqwest",
+        "console",
+        "cliclack",
+        "indicatif",
+        "itertools",
+        "log",
+    ];
+
+    let name: String = cliclack::input("Search crates")
+        .placeholder("Type to search a slow registry")
+        .autocomplete_async(
+            move |query: &str| {
+                // Pretend querying a slow remote registry.
+                thread::sleep(Duration::from_millis(700));
+                crates
+                    .iter()
+                    .filter(|name| name.contains(query))
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>()
+            },
+            Duration::from_millis(250),
+        )
+        .interact()?;
+
+    cliclack::outro(format!("Selected: {name}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::ops::Range;
+
+use cliclack::{path::PathCompletion, Suggest};
+use console::style;
+
+/// Completes a `cargo` command line word by word: a subcommand, a flag,
+/// or a file path under the cursor.
+struct Cargo {
+    files: PathCompletion,
+}
+
+impl Cargo {
+    const SUBCOMMANDS: [&'static str; 6] = ["build", "check", "clippy", "run", "test", "doc"];
+    const FLAGS: [&'static str; 5] = [
+        "--release",
+        "--verbose",
+        "--workspace",
+        "--all-features",
+        "--manifest-path",
+    ];
+
+    /// Returns the range of the word under the cursor.
+    fn word(input: &[char], cursor: usize) -> Range<usize> {
+        let start = input[..cursor]
+            .iter()
+            .rposition(|c| c.is_whitespace())
+            .map_or(0, |i| i + 1);
+        let end = input[cursor..]
+            .iter()
+            .position(|c| c.is_whitespace())
+            .map_or(input.len(), |i| cursor + i);
+        start..end
+    }
+}
+
+impl Suggest for Cargo {
+    type Result = String;
+
+    fn suggest(&self, input: &str) -> Vec<String> {
+        self.suggest_at(input, input.chars().count()).1
+    }
+
+    fn suggest_at(&self, input: &str, cursor: usize) -> (Range<usize>, Vec<String>) {
+        let chars: Vec<char> = input.chars().collect();
+        let range = Self::word(&chars, cursor);
+        let word: String = chars[range.start..cursor].iter().collect();
+
+        let candidates = if range.start == 0 {
+            Self::SUBCOMMANDS.map(String::from).to_vec()
+        } else if word.starts_with('-') {
+            Self::FLAGS.map(String::from).to_vec()
+        } else if word.is_empty() {
+            vec![]
+        } else {
+            return (range, self.files.suggest(&word));
+        };
+
+        let suggestions = candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(&word))
+            .collect();
+
+        (range, suggestions)
+    }
+
+    fn complete(&self, input: &str) -> Option<String> {
+        self.files.complete(input)
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    cliclack::intro(style(" command line ").on_cyan().black())?;
+
+    let command: String = cliclack::input("cargo")
+        .placeholder("Type a subcommand, a --flag, or a file path")
+        .autocomplete(Cargo {
+            files: PathCompletion::new(),
+        })
+        .autocomplete_shell_style()
+        .interact()?;
+
+    cliclack::outro(format!("Running: cargo {command}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use clap::{Parser, ValueEnum};
+use cliclack::{intro, outro_note};
+use console::style;
+
+#[derive(Clone, ValueEnum)]
+enum Kind {
+    /// Node.js with TypeScript
+    Ts,
+    /// Plain JavaScript
+    Js,
+    /// You'd better not
+    Coffee,
+}
+
+/// Try running without arguments, or with some of them, e.g. `-- --port 80`.
+#[derive(Parser)]
+struct Cli {
+    /// Where should we create your project?
+    path: String,
+
+    /// The type of the project
+    #[arg(long, value_enum)]
+    kind: Kind,
+
+    /// A port of the dev server
+    #[arg(long, required = true)]
+    port: Option<u16>,
+
+    /// Install dependencies
+    #[arg(long, required = true)]
+    install: bool,
+}
+
+fn main() -> std::io::Result<()> {
+    intro(style(" clap ").on_cyan().black())?;
+
+    let cli: Cli = cliclack::clap::parse()?;
+
+    outro_note(
+        "Done!",
+        format!(
+            "Path: {}\nKind: {}\nPort: {:?}\nInstall: {}",
+            cli.path,
+            cli.kind.to_possible_value().unwrap().get_name(),
+            cli.port,
+            cli.install
+        ),
+    )?;
+
+    Ok(())
+}
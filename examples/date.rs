@@ -0,0 +1,20 @@
+use chrono::{Datelike, Local, Months, Weekday};
+use cliclack::{date, intro, outro};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" date ").on_cyan().black())?;
+
+    let today = Local::now().date_naive();
+
+    let window = date("Pick a maintenance window")
+        .min(today)
+        .max(today + Months::new(3))
+        .disabled(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+        .first_weekday(Weekday::Sun)
+        .interact()?;
+
+    outro(format!("Scheduled on {window}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use cliclack::{intro, outro_note, Prompt};
+use console::style;
+
+#[derive(Prompt, Clone, PartialEq, Eq, Debug)]
+#[prompt("Pick a project type")]
+enum Kind {
+    #[prompt("TypeScript")]
+    Ts,
+    #[prompt("JavaScript")]
+    Js,
+    #[prompt("CoffeeScript", hint = "oh no")]
+    Coffee,
+}
+
+#[derive(Prompt, Clone, PartialEq, Eq, Debug)]
+enum Tool {
+    #[prompt("Prettier", hint = "recommended")]
+    Prettier,
+    #[prompt("ESLint", hint = "recommended")]
+    Eslint,
+    #[prompt("GitHub Action")]
+    GhAction,
+}
+
+#[derive(Prompt)]
+struct Project {
+    #[prompt(
+        "What is the project name?",
+        placeholder = "sparkling-solid",
+        validate = |input: &String| match input.is_empty() {
+            true => Err("Please enter a name."),
+            false => Ok(()),
+        }
+    )]
+    name: String,
+    #[prompt("Pick a project type", default = "TypeScript")]
+    kind: Kind,
+    #[prompt("Select additional tools")]
+    tools: Vec<Tool>,
+    #[prompt("Which port to use?", default = "3000")]
+    port: u16,
+    #[prompt("Describe the project")]
+    description: Option<String>,
+    #[prompt("Pick a license", choices = ["MIT", "Apache-2.0", "GPL-3.0"])]
+    license: String,
+    #[prompt("Install dependencies?", default = true)]
+    install: bool,
+}
+
+fn main() -> std::io::Result<()> {
+    intro(style(" derive ").on_cyan().black())?;
+
+    let project = Project::interact()?;
+
+    outro_note(
+        format!("Project: {}", project.name),
+        format!(
+            "Type: {:?}\nTools: {:?}\nPort: {}\nDescription: {:?}\nLicense: {}\nInstall: {}",
+            project.kind,
+            project.tools,
+            project.port,
+            project.description,
+            project.license,
+            project.install,
+        ),
+    )?;
+
+    Ok(())
+}
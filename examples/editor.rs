@@ -0,0 +1,22 @@
+use cliclack::{input, intro, outro_note};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" editor ").on_cyan().black())?;
+
+    let message: String = input("Commit message")
+        .placeholder("Press Ctrl-X Ctrl-E to open $VISUAL or $EDITOR")
+        .multiline()
+        .editor()
+        .interact()?;
+
+    // The lines are joined with spaces in a single-line input.
+    let title: String = input("Title")
+        .placeholder("Press Ctrl-X Ctrl-E to open the editor")
+        .editor()
+        .interact()?;
+
+    outro_note("Done!", format!("Title: {title}\nMessage:\n{message}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use cliclack::{file_picker, intro, outro_note};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" file picker ").on_cyan().black())?;
+
+    let source = file_picker("Pick a source file")
+        .extensions(["rs", "toml"])
+        .interact()?;
+
+    let assets = file_picker("Pick assets (Space to select)")
+        .max_rows(5)
+        .interact_multiple()?;
+
+    let output = file_picker("Pick an output directory")
+        .directories_only()
+        .interact()?;
+
+    outro_note(
+        "Picked",
+        format!(
+            "Source: {}\nAssets: {assets:?}\nOutput: {}",
+            source.display(),
+            output.display()
+        ),
+    )?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use cliclack::{outro, select};
+use cliclack::{matcher::Subsequence, outro, select};
 
 fn main() -> std::io::Result<()> {
     let selected = select("Select a word")
@@ -24,7 +24,20 @@ fn main() -> std::io::Result<()> {
         .filter_mode()
         .interact()?;
 
-    outro(format!("You chose: {selected}, then {}", tools.join(", ")))?;
+    // fzf-like matching: try "fp" or "mcr".
+    let file = select("Open a file")
+        .item("src/file_picker.rs", "src/file_picker.rs", "")
+        .item("src/filter.rs", "src/filter.rs", "")
+        .item("src/matcher.rs", "src/matcher.rs", "")
+        .item("src/multiselect.rs", "src/multiselect.rs", "")
+        .item("src/prompt/cursor.rs", "src/prompt/cursor.rs", "")
+        .filter_matcher(Subsequence::new())
+        .interact()?;
+
+    outro(format!(
+        "You chose: {selected}, then {}, and opened {file}",
+        tools.join(", ")
+    ))?;
 
     Ok(())
 }
@@ -0,0 +1,22 @@
+use cliclack::{highlight, input, intro, outro_note};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" highlight ").on_cyan().black())?;
+
+    let json: String = input("JSON")
+        .placeholder(r#"{"name": "cliclack", "stars": 1000}"#)
+        .highlight(highlight::Json)
+        .interact()?;
+
+    let toml: String = input("TOML")
+        .multiline()
+        .line_numbers()
+        .default_input("[package]\nname = \"cliclack\"\nversion = \"0.3\"")
+        .highlight(highlight::Toml)
+        .interact()?;
+
+    outro_note("Your input", format!("{json}\n\n{toml}"))?;
+
+    Ok(())
+}
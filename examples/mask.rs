@@ -0,0 +1,29 @@
+use cliclack::{input, intro, outro_note};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" mask ").on_cyan().black())?;
+
+    let phone = input("Phone number")
+        .mask_pattern("(###) ###-####")
+        .interact_masked()?;
+
+    let mac: String = input("MAC address")
+        .mask_pattern("HH:HH:HH:HH:HH:HH")
+        .interact()?;
+
+    let date: String = input("Date")
+        .placeholder("DD/MM/YYYY")
+        .mask_pattern("##/##/####")
+        .interact()?;
+
+    outro_note(
+        "Done!",
+        format!(
+            "Phone: {} (raw: {})\nMAC: {mac}\nDate: {date}",
+            phone.formatted, phone.raw
+        ),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use cliclack::{intro, number, outro_note};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" number ").on_cyan().black())?;
+
+    let port: u16 = number("Port")
+        .min(1024)
+        .default_value(8080)
+        .page_step(1000)
+        .interact()?;
+
+    let ratio: f64 = number("Compression ratio")
+        .placeholder("Use Up/Down to change")
+        .min(0.0)
+        .max(1.0)
+        .step(0.1)
+        .interact()?;
+
+    outro_note("Done!", format!("Port: {port}\nRatio: {ratio}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use cliclack::{input, intro, outro_note, path};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" path ").on_cyan().black())?;
+
+    let manifest: String = input("Path to a manifest")
+        .placeholder("Type and press Tab to complete")
+        .autocomplete(path::PathCompletion::new().extensions(["toml"]))
+        .validate(path::exists())
+        .interact()?;
+
+    let output: String = input("Output directory")
+        .autocomplete(path::PathCompletion::new().directories_only())
+        .validate(path::writable())
+        .interact()?;
+
+    outro_note(
+        "Paths",
+        format!(
+            "Manifest: {}\nOutput: {}",
+            path::expand_tilde(&manifest).display(),
+            path::expand_tilde(&output).display()
+        ),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use cliclack::{duration, intro, outro_note, time};
+use console::style;
+
+fn main() -> std::io::Result<()> {
+    intro(style(" time ").on_cyan().black())?;
+
+    let start = time("Start the maintenance at").interact()?;
+
+    let length = duration("How long will it take?")
+        .default_value(Duration::from_secs(90 * 60))
+        .interact()?;
+
+    outro_note(
+        "Scheduled",
+        format!("Start: {start}\nDuration: {}s", length.as_secs()),
+    )?;
+
+    Ok(())
+}
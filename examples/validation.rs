@@ -14,12 +14,14 @@ fn main() -> std::io::Result<()> {
     cliclack::intro(style(" interactive validation ").on_cyan().black())?;
 
     let username: String = cliclack::input("Username (not 'alice' or 'bob')")
+        .max_len(16)
         .validate_interactively(|x: &String| (x.len() > 2).then_some(()).ok_or("too short"))
         .validate_on_enter(check_username_is_available)
         .interact()?;
 
     let _password = cliclack::password("Provide a password")
         .mask('▪')
+        .max_len(64)
         .validate_interactively(|x: &String| {
             if x.len() < 8 {
                 Err("password should be at least 8 characters long")
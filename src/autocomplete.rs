@@ -1,34 +1,421 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
 use console::Key;
 
-use crate::{prompt::interaction::State, theme::THEME, Suggest};
+use crate::{
+    prompt::interaction::State,
+    suggest::common_prefix,
+    theme::{Theme, THEME},
+    view::ListView,
+    Suggest, Suggestion, Untyped,
+};
 
-pub(crate) struct Autocomplete {
-    /// The list of suggestions to be rendered.
-    source: Box<dyn Suggest<Result = String>>,
+/// An autocomplete suggestion with an optional value.
+pub(crate) struct Item<V> {
+    label: String,
+    hint: String,
+    insert: String,
+    value: Option<V>,
+}
+
+impl<V> From<String> for Item<V> {
+    fn from(label: String) -> Self {
+        Self {
+            insert: label.clone(),
+            label,
+            hint: String::new(),
+            value: None,
+        }
+    }
+}
+
+impl<V> From<Suggestion<V>> for Item<V> {
+    fn from(suggestion: Suggestion<V>) -> Self {
+        Self {
+            insert: suggestion
+                .insert
+                .unwrap_or_else(|| suggestion.label.clone()),
+            label: suggestion.label,
+            hint: suggestion.hint,
+            value: suggestion.value,
+        }
+    }
+}
+
+impl Item<Untyped> {
+    /// Gives the value type to the item without a value.
+    fn typed<V>(self) -> Item<V> {
+        Item {
+            label: self.label,
+            hint: self.hint,
+            insert: self.insert,
+            value: self.value.map(|untyped| match untyped {}),
+        }
+    }
+}
+
+/// Converts suggestions of the source into autocomplete items.
+struct Items<S, V>(S, PhantomData<fn() -> V>);
+
+impl<S, V> Items<S, V> {
+    fn new(source: S) -> Self {
+        Self(source, PhantomData)
+    }
+}
+
+impl<S, V> Suggest for Items<S, V>
+where
+    S: Suggest,
+    S::Result: Into<Item<V>>,
+{
+    type Result = Item<V>;
+
+    fn suggest(&self, input: &str) -> Vec<Item<V>> {
+        self.0.suggest(input).into_iter().map(Into::into).collect()
+    }
+
+    fn suggest_at(&self, input: &str, cursor: usize) -> (Range<usize>, Vec<Item<V>>) {
+        let (range, items) = self.0.suggest_at(input, cursor);
+        (range, items.into_iter().map(Into::into).collect())
+    }
+
+    fn complete(&self, input: &str) -> Option<String> {
+        self.0.complete(input)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        self.0.positions(label, query)
+    }
+}
+
+/// Gives the value type to suggestions of an untyped source.
+struct Typed<V>(
+    Box<dyn Suggest<Result = Item<Untyped>>>,
+    PhantomData<fn() -> V>,
+);
+
+impl<V> Suggest for Typed<V> {
+    type Result = Item<V>;
+
+    fn suggest(&self, input: &str) -> Vec<Item<V>> {
+        self.0.suggest(input).into_iter().map(Item::typed).collect()
+    }
+
+    fn suggest_at(&self, input: &str, cursor: usize) -> (Range<usize>, Vec<Item<V>>) {
+        let (range, items) = self.0.suggest_at(input, cursor);
+        (range, items.into_iter().map(Item::typed).collect())
+    }
+
+    fn complete(&self, input: &str) -> Option<String> {
+        self.0.complete(input)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        self.0.positions(label, query)
+    }
+}
+
+/// A suggestion source shared with the background thread.
+struct Shared<S>(Arc<S>);
+
+impl<S: Suggest> Suggest for Shared<S> {
+    type Result = S::Result;
+
+    fn suggest(&self, input: &str) -> Vec<S::Result> {
+        self.0.suggest(input)
+    }
+
+    fn suggest_at(&self, input: &str, cursor: usize) -> (Range<usize>, Vec<S::Result>) {
+        self.0.suggest_at(input, cursor)
+    }
+
+    fn complete(&self, input: &str) -> Option<String> {
+        self.0.complete(input)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        self.0.positions(label, query)
+    }
+}
+
+/// An input and a cursor position to compute suggestions for.
+type Query = (String, usize);
+
+/// Computes suggestions in a background thread.
+struct Background {
+    /// Sends numbered queries to the background thread.
+    queries: Sender<(usize, Query)>,
+    /// Receives suggestions and token ranges for the numbered queries.
+    results: Receiver<(usize, Range<usize>, Vec<String>)>,
+    /// The number of the latest query: results for older queries are stale.
+    latest: usize,
+    /// The latest query.
+    query: Query,
+    /// When the latest query was sent, if its results are still pending.
+    pending: Option<Instant>,
+}
+
+impl Background {
+    fn new<S>(source: Arc<S>, debounce: Duration) -> Self
+    where
+        S: Suggest<Result = String> + Send + Sync + 'static,
+    {
+        let (queries, query_receiver) = mpsc::channel::<(usize, Query)>();
+        let (result_sender, results) = mpsc::channel();
+
+        // The thread stops when the autocompletion is dropped.
+        thread::spawn(move || {
+            while let Ok(mut query) = query_receiver.recv() {
+                // Debouncing: wait until no newer query is typed for a while.
+                loop {
+                    match query_receiver.recv_timeout(debounce) {
+                        Ok(newer) => query = newer,
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+
+                let (number, (input, cursor)) = query;
+                let (range, items) = source.suggest_at(&input, cursor);
+                if result_sender.send((number, range, items)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            queries,
+            results,
+            latest: 0,
+            query: Query::default(),
+            pending: None,
+        }
+    }
+}
+
+/// Replaces the token of the input with an accepted suggestion.
+pub(crate) struct Completion {
+    /// The character range of the token.
+    pub range: Range<usize>,
+    /// The inserted text.
+    pub text: String,
+}
+
+/// Suggestions of the input, which values are of the type `V`.
+pub(crate) struct Autocomplete<V> {
+    /// The source of suggestions.
+    source: Box<dyn Suggest<Result = Item<V>>>,
     /// The list of suggestions to be rendered.
-    items: Vec<String>,
+    items: Vec<Item<V>>,
+    /// The token the suggestions are computed for.
+    query: String,
+    /// The number of characters before and after the token in the input,
+    /// which keeps the token range valid while the token is being edited.
+    token: (usize, usize),
     /// The index of the currently selected suggestion (unselected by default).
     cursor: Option<usize>,
+    /// Computes suggestions in the background if the source is asynchronous.
+    background: Option<Background>,
+    /// The visible window of suggestions.
+    view: ListView,
+    /// The index, the inserted text and the value of the last accepted suggestion.
+    accepted: Option<(usize, String, V)>,
+    /// Completes the common prefix and cycles through suggestions on `Tab`.
+    shell_style: bool,
+}
+
+impl Autocomplete<Untyped> {
+    /// Gives the value type to the suggestions without values.
+    pub fn typed<V: 'static>(self) -> Autocomplete<V> {
+        Autocomplete {
+            source: Box::new(Typed(self.source, PhantomData)),
+            items: self.items.into_iter().map(Item::typed).collect(),
+            query: self.query,
+            token: self.token,
+            cursor: self.cursor,
+            background: self.background,
+            view: self.view,
+            accepted: None,
+            shell_style: self.shell_style,
+        }
+    }
 }
 
-impl Autocomplete {
+impl<V: 'static> Autocomplete<V> {
     /// Creates a new autocompletion popup with the given suggestions.
     pub fn new<S>(suggestions: S) -> Self
     where
-        S: Suggest<Result = String> + 'static,
+        S: Suggest + 'static,
+        S::Result: Into<Item<V>>,
     {
         Self {
-            source: Box::new(suggestions),
+            source: Box::new(Items::new(suggestions)),
             items: Vec::new(),
+            query: String::new(),
+            token: (0, 0),
             cursor: None,
+            background: None,
+            view: ListView::default(),
+            accepted: None,
+            shell_style: false,
         }
     }
 
-    /// Tracks the state of the autocompletion popup.
-    pub fn on(&mut self, key: &Key, query: &str) -> Option<State<String>> {
+    /// Creates a new autocompletion popup with suggestions computed in the
+    /// background after the input stops changing for the `debounce` interval.
+    pub fn new_async<S>(suggestions: S, debounce: Duration) -> Self
+    where
+        S: Suggest<Result = String> + Send + Sync + 'static,
+    {
+        let source = Arc::new(suggestions);
+
+        Self {
+            source: Box::new(Items::new(Shared(Arc::clone(&source)))),
+            items: Vec::new(),
+            query: String::new(),
+            token: (0, 0),
+            cursor: None,
+            background: Some(Background::new(source, debounce)),
+            view: ListView::default(),
+            accepted: None,
+            shell_style: false,
+        }
+    }
+}
+
+impl<V> Autocomplete<V> {
+    /// Sets the maximum visible number of suggestions.
+    pub fn max_rows(&mut self, height: usize) {
+        self.view.height = height.max(1);
+    }
+
+    /// Returns the visible window of suggestions following the cursor
+    /// (capped in case the suggestions list has shrunk).
+    fn window(&self) -> ListView {
+        let len = self.items.len();
+        let height = self.view.height.min(len);
+        let mut start = self.view.start.min(len - height);
+
+        if let Some(cursor) = self.cursor.map(|cursor| cursor.min(len.saturating_sub(1))) {
+            if cursor < start {
+                start = cursor;
+            } else if cursor >= start + height {
+                start = cursor + 1 - height;
+            }
+        }
+
+        ListView { height, start }
+    }
+
+    /// Enables the shell-style `Tab` completion.
+    pub fn shell_style(&mut self) {
+        self.shell_style = true;
+    }
+
+    /// Refreshes the suggestions for the input at the cursor: immediately,
+    /// or in the background.
+    fn refresh(&mut self, input: &str, cursor: usize) {
+        self.accepted = None;
+        match &mut self.background {
+            // The same query is being computed already.
+            Some(background)
+                if background.pending.is_some()
+                    && background.query.0 == input
+                    && background.query.1 == cursor => {}
+            Some(background) => {
+                background.latest += 1;
+                background.query = (input.into(), cursor);
+                background.pending = Some(Instant::now());
+                background
+                    .queries
+                    .send((background.latest, background.query.clone()))
+                    .ok();
+            }
+            None => {
+                let (range, items) = self.source.suggest_at(input, cursor);
+                self.items = items;
+                self.set_token(input, range);
+            }
+        }
+    }
+
+    /// Remembers the token of the input the suggestions are computed for.
+    fn set_token(&mut self, input: &str, range: Range<usize>) {
+        let chars: Vec<char> = input.chars().collect();
+        let end = range.end.min(chars.len());
+        let start = range.start.min(end);
+
+        self.query = chars[start..end].iter().collect();
+        self.token = (start, chars.len() - end);
+    }
+
+    /// Returns the range of the token in the input, assuming that only
+    /// the token has been edited since the suggestions were computed.
+    fn token_range(&self, input: &str) -> Range<usize> {
+        let len = input.chars().count();
+        let start = self.token.0.min(len);
+        start..len.saturating_sub(self.token.1).max(start)
+    }
+
+    /// Returns the token of the input, see [`Autocomplete::token_range`].
+    fn token(&self, input: &str) -> String {
+        let range = self.token_range(input);
+        input.chars().skip(range.start).take(range.len()).collect()
+    }
+
+    /// Clears the suggestions discarding the pending ones.
+    fn clear(&mut self) {
+        self.items.clear();
+        self.query.clear();
+        self.token = (0, 0);
+        if let Some(background) = &mut self.background {
+            background.latest += 1;
+            background.pending = None;
+        }
+    }
+
+    /// Takes the suggestions computed in the background, discarding stale ones.
+    pub fn poll(&mut self) {
+        let Some(background) = &mut self.background else {
+            return;
+        };
+
+        let mut latest = None;
+        while let Ok((number, range, items)) = background.results.try_recv() {
+            if number == background.latest && background.pending.is_some() {
+                background.pending = None;
+                latest = Some((background.query.0.clone(), range, items));
+            }
+        }
+
+        if let Some((input, range, items)) = latest {
+            self.items = items.into_iter().map(Item::from).collect();
+            self.set_token(&input, range);
+        }
+    }
+
+    /// Returns `true` if the suggestions are being computed in the background.
+    pub fn is_pending(&self) -> bool {
+        self.background
+            .as_ref()
+            .is_some_and(|background| background.pending.is_some())
+    }
+
+    /// Tracks the state of the autocompletion popup for the input with
+    /// the cursor at the given character position.
+    pub fn on(&mut self, key: &Key, input: &str, position: usize) -> Option<State<Completion>> {
         if self.items.is_empty() {
-            self.items = self.source.suggest(query);
-            return None;
+            self.refresh(input, position);
+            // In the shell style, `Tab` completes the suggestions right away.
+            if !(self.shell_style && *key == Key::Tab) || self.items.is_empty() {
+                return None;
+            }
         }
 
         let len = self.items.len();
@@ -47,33 +434,167 @@ impl Autocomplete {
             Key::ArrowUp => self.cursor = Some(cursor.saturating_sub(1) % len),
             // Move the cursor down in a circular manner.
             Key::ArrowDown => self.cursor = Some((cursor + 1) % len),
+            // Shell style: accept the only suggestion, complete the common prefix,
+            // or start cycling through the suggestions.
+            Key::Tab if self.shell_style && self.cursor.is_none() => {
+                if len == 1 {
+                    return Some(self.accept(0, input));
+                }
+
+                let range = self.token_range(input);
+                let token = self.token(input);
+                let inserts: Vec<String> = self.items.iter().map(|i| i.insert.clone()).collect();
+                // Suggestions match case-insensitively, so the prefix may only
+                // fix the case of the token (like `RU` completed to `ru`).
+                let completion = self
+                    .source
+                    .complete(&token)
+                    .or_else(|| common_prefix(&inserts, true))
+                    .filter(|prefix| match prefix.chars().count().cmp(&range.len()) {
+                        Ordering::Greater => true,
+                        Ordering::Equal => *prefix != token,
+                        Ordering::Less => false,
+                    });
+
+                return Some(match completion {
+                    Some(completion) => self.complete(input, completion),
+                    None => self.accept(0, input),
+                });
+            }
+            // Shell style: cycle through the suggestions.
+            Key::Tab if self.shell_style => return Some(self.accept((cursor + 1) % len, input)),
+            Key::BackTab if self.shell_style => {
+                return Some(self.accept((cursor + len - 1) % len, input))
+            }
+            // Complete the input if nothing is highlighted (e.g. the common prefix).
+            Key::Tab if self.cursor.is_none() => {
+                let token = self.token(input);
+                let completion = self.source.complete(&token)?;
+                return Some(self.complete(input, completion));
+            }
             // Submit the currently highlighted suggestion if cursor is set.
             Key::Tab | Key::Enter => {
-                return self
-                    .cursor
-                    .map(|_| State::Submit(self.items[cursor].clone()))
+                self.cursor?;
+                return Some(self.accept(cursor, input));
             }
             // Hide the autocompletion drop-down, and tell the prompt to not close the entire app.
             Key::Escape => {
-                self.items.clear();
+                self.clear();
                 self.cursor = None;
                 return Some(State::Cancel);
             }
-            // Other keys may clear suggestions if the query is empty.
-            _ if query.is_empty() => self.items.clear(),
-            // Other keys refresh the suggestions.
-            _ => self.items = self.source.suggest(query),
+            // Other keys may clear suggestions if the input is empty.
+            _ if input.is_empty() => self.clear(),
+            // Other keys refresh the suggestions, and restart the shell-style cycling.
+            _ => {
+                self.refresh(input, position);
+                if self.shell_style {
+                    self.cursor = None;
+                }
+            }
         }
+
+        self.view.start = self.window().start;
         None
     }
 
+    /// Replaces the token with the completion and refreshes the suggestions.
+    fn complete(&mut self, input: &str, text: String) -> State<Completion> {
+        let completion = Completion {
+            range: self.token_range(input),
+            text,
+        };
+
+        let mut chars: Vec<char> = input.chars().collect();
+        chars.splice(completion.range.clone(), completion.text.chars());
+        let position = completion.range.start + completion.text.chars().count();
+        self.refresh(&String::from_iter(chars), position);
+
+        State::Submit(completion)
+    }
+
+    /// Highlights and accepts the suggestion, keeping its value.
+    fn accept(&mut self, index: usize, input: &str) -> State<Completion> {
+        // Give the value of the previously accepted suggestion back.
+        if let Some((previous, _, value)) = self.accepted.take() {
+            if let Some(item) = self.items.get_mut(previous) {
+                item.value.get_or_insert(value);
+            }
+        }
+
+        self.cursor = Some(index);
+        self.view.start = self.window().start;
+        let range = self.token_range(input);
+
+        let item = &mut self.items[index];
+        self.accepted = item
+            .value
+            .take()
+            .map(|value| (index, item.insert.clone(), value));
+
+        State::Submit(Completion {
+            range,
+            text: item.insert.clone(),
+        })
+    }
+
+    /// Returns `true` if the token of the input exactly matches one of
+    /// the current suggestions: the latest ones computed in the background, if any.
+    ///
+    /// Doesn't compute suggestions, so a slow source never blocks submitting.
+    pub fn is_known(&self, input: &str) -> bool {
+        let token = self.token(input);
+        self.items.iter().any(|item| item.insert == token)
+    }
+
+    /// Takes the value of the accepted suggestion if the token of the input
+    /// is still the text inserted by the suggestion.
+    pub fn take_value(&mut self, input: &str) -> Option<V> {
+        let (_, insert, value) = self.accepted.take()?;
+        (insert == self.token(input)).then_some(value)
+    }
+
+    /// Returns the rest of the top suggestion starting with the token at the
+    /// end of the input (or of the highlighted one) to be shown after
+    /// the cursor as ghost text.
+    pub fn ghost(&self, input: &str) -> Option<String> {
+        let range = self.token_range(input);
+        if self.token.1 > 0 || range.is_empty() {
+            return None;
+        }
+
+        let query: String = input.chars().skip(range.start).collect();
+        let query = query.as_str();
+
+        let completes =
+            |item: &&Item<V>| item.insert.len() > query.len() && item.insert.starts_with(query);
+        let item = match self.cursor {
+            Some(cursor) => self
+                .items
+                .get(cursor.min(self.items.len().saturating_sub(1)))
+                .filter(completes),
+            None => self.items.iter().find(completes),
+        };
+
+        item.map(|item| item.insert[query.len()..].to_string())
+    }
+
     /// Renders autocomplete popup suggestions under the input line.
     pub fn render<T>(&self, state: &State<T>) -> String {
+        self.render_with(&**THEME.read().unwrap(), state)
+    }
+
+    fn render_with<T>(&self, theme: &dyn Theme, state: &State<T>) -> String {
         match state {
             State::Submit(_) | State::Cancel => return String::new(),
             _ => {}
         }
 
+        if let Some(started) = self.background.as_ref().and_then(|b| b.pending) {
+            let frame = started.elapsed().as_millis() / 100;
+            return theme.format_autocomplete_pending(&state.into(), frame as usize);
+        }
+
         if self.items.is_empty() {
             return String::new();
         }
@@ -82,13 +603,404 @@ impl Autocomplete {
         // It allows to keep the original cursor position unless arrows are pressed.
         let cursor = self.cursor.map(|line| line.min(self.items.len() - 1));
 
-        let theme = THEME.read().unwrap();
+        let window = self.window();
+        let hidden = self.items.len() - window.height;
 
         let empty_line = [/*theme.format_autocomplete_item(&state.into(), false, "")*/].into_iter();
-        let items = self.items.iter().enumerate().map(|(i, item)| {
-            theme.format_autocomplete_item(&state.into(), cursor == Some(i), item)
-        });
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(window.start)
+            .take(window.height)
+            .map(|(i, item)| {
+                theme.format_autocomplete_suggestion(
+                    &state.into(),
+                    cursor == Some(i),
+                    &item.label,
+                    &item.hint,
+                    &self.source.positions(&item.label, &self.query),
+                )
+            });
+        let more = (hidden > 0).then(|| theme.format_autocomplete_more(&state.into(), hidden));
+
+        empty_line.chain(items).chain(more).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Suggests the words containing the input (case-insensitively) in order.
+    fn words(list: &'static [&'static str]) -> impl Fn(&str) -> Vec<String> {
+        move |input: &str| {
+            list.iter()
+                .filter(|word| word.to_lowercase().contains(&input.to_lowercase()))
+                .map(|word| word.to_string())
+                .collect()
+        }
+    }
+
+    /// Tracks the typed input with the cursor at the end.
+    fn write(autocomplete: &mut Autocomplete<Untyped>, input: &str) {
+        let key = Key::Char(input.chars().last().unwrap_or(' '));
+        autocomplete.on(&key, input, input.chars().count());
+    }
+
+    #[test]
+    fn ghost() {
+        let mut autocomplete = Autocomplete::new(words(&["Rust", "rustup", "ruby"]));
+        write(&mut autocomplete, "ru");
+
+        // The top suggestion starting with the input (case-sensitively).
+        assert_eq!(autocomplete.ghost("ru").as_deref(), Some("stup"));
+        assert_eq!(autocomplete.ghost("Ru").as_deref(), Some("st"));
+
+        // The highlighted suggestion, if it starts with the input.
+        autocomplete.on(&Key::ArrowDown, "ru", 2);
+        assert_eq!(autocomplete.ghost("ru"), None);
+        autocomplete.on(&Key::ArrowDown, "ru", 2);
+        autocomplete.on(&Key::ArrowDown, "ru", 2);
+        assert_eq!(autocomplete.ghost("ru").as_deref(), Some("by"));
+
+        // Nothing to complete.
+        assert_eq!(autocomplete.ghost("ruby"), None);
+        assert_eq!(autocomplete.ghost(""), None);
+    }
+
+    /// A slow source: reports the query it's called with, and returns
+    /// suggestions only when released.
+    fn gated() -> (
+        impl Fn(&str) -> Vec<String> + Send + Sync,
+        Receiver<String>,
+        Sender<()>,
+    ) {
+        let (calls, called) = mpsc::channel();
+        let (release, released) = mpsc::channel::<()>();
+        let (calls, released) = (Mutex::new(calls), Mutex::new(released));
+
+        let source = move |input: &str| {
+            calls.lock().unwrap().send(input.to_string()).ok();
+            released.lock().unwrap().recv().ok();
+            vec![format!("{input}1"), format!("{input}2")]
+        };
+        (source, called, release)
+    }
+
+    /// Polls the background results until they aren't pending.
+    fn wait(autocomplete: &mut Autocomplete<Untyped>) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while autocomplete.is_pending() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(5));
+            autocomplete.poll();
+        }
+    }
+
+    fn labels(autocomplete: &Autocomplete<Untyped>) -> Vec<&str> {
+        autocomplete
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn debounce() {
+        let (source, called, release) = gated();
+        let mut autocomplete = Autocomplete::new_async(source, Duration::from_millis(200));
+
+        // Only the last query typed within the debounce interval is computed.
+        for input in ["a", "ab", "abc"] {
+            write(&mut autocomplete, input);
+            assert!(autocomplete.is_pending());
+        }
+        assert_eq!(called.recv().unwrap(), "abc");
+        release.send(()).unwrap();
+
+        wait(&mut autocomplete);
+        assert_eq!(labels(&autocomplete), ["abc1", "abc2"]);
+        assert!(called.try_recv().is_err());
+    }
+
+    #[test]
+    fn stale_results() {
+        let (source, called, release) = gated();
+        let mut autocomplete = Autocomplete::new_async(source, Duration::from_millis(1));
+
+        write(&mut autocomplete, "a");
+        assert_eq!(called.recv().unwrap(), "a");
+
+        // The input changes while the suggestions are computed.
+        write(&mut autocomplete, "ab");
+        release.send(()).unwrap();
+        assert_eq!(called.recv().unwrap(), "ab");
+
+        // The results for `a` are discarded.
+        autocomplete.poll();
+        assert!(autocomplete.is_pending());
+        assert!(labels(&autocomplete).is_empty());
+
+        release.send(()).unwrap();
+        wait(&mut autocomplete);
+        assert_eq!(labels(&autocomplete), ["ab1", "ab2"]);
+
+        // Clearing the input discards the pending results.
+        write(&mut autocomplete, "abc");
+        assert_eq!(called.recv().unwrap(), "abc");
+        autocomplete.on(&Key::Backspace, "", 0);
+        release.send(()).unwrap();
+        thread::sleep(Duration::from_millis(50));
+        autocomplete.poll();
+        assert!(!autocomplete.is_pending());
+        assert!(labels(&autocomplete).is_empty());
+    }
+
+    /// Presses the key with the cursor at the end of the input, returning
+    /// the text replacing the token.
+    fn complete(autocomplete: &mut Autocomplete<Untyped>, key: Key, input: &str) -> Option<String> {
+        match autocomplete.on(&key, input, input.chars().count())? {
+            State::Submit(completion) => Some(completion.text),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn shell_style() {
+        let mut autocomplete = Autocomplete::new(words(&["rust", "rustup", "ruby"]));
+        autocomplete.shell_style();
+
+        // The common prefix first, then cycling from the top suggestion.
+        write(&mut autocomplete, "r");
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "r").as_deref(),
+            Some("ru")
+        );
+        assert_eq!(autocomplete.cursor, None);
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "ru").as_deref(),
+            Some("rust")
+        );
+        assert_eq!(autocomplete.cursor, Some(0));
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "rust").as_deref(),
+            Some("rustup")
+        );
+        assert_eq!(
+            complete(&mut autocomplete, Key::BackTab, "rustup").as_deref(),
+            Some("rust")
+        );
+
+        // `Shift-Tab` wraps around to the bottom.
+        assert_eq!(
+            complete(&mut autocomplete, Key::BackTab, "rust").as_deref(),
+            Some("ruby")
+        );
+
+        // Typing restarts the cycling, and the only suggestion is accepted.
+        write(&mut autocomplete, "rub");
+        assert_eq!(autocomplete.cursor, None);
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "rub").as_deref(),
+            Some("ruby")
+        );
+
+        // `Tab` right after typing completes without showing suggestions first.
+        let mut autocomplete = Autocomplete::new(words(&["rust", "rustup"]));
+        autocomplete.shell_style();
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "ru").as_deref(),
+            Some("rust")
+        );
+    }
+
+    #[test]
+    fn shell_style_case() {
+        let mut autocomplete = Autocomplete::new(words(&["Rust", "ruby"]));
+        autocomplete.shell_style();
+
+        // The prefix is common case-insensitively, like matching.
+        write(&mut autocomplete, "r");
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "r").as_deref(),
+            Some("Ru")
+        );
+
+        // Only the case of the token is fixed first.
+        write(&mut autocomplete, "RU");
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "RU").as_deref(),
+            Some("Ru")
+        );
+        assert_eq!(autocomplete.cursor, None);
+        assert_eq!(
+            complete(&mut autocomplete, Key::Tab, "Ru").as_deref(),
+            Some("Rust")
+        );
+    }
+
+    /// Suggests flags with values for the word under the cursor.
+    struct Flags;
+
+    impl Suggest for Flags {
+        type Result = Suggestion<u8>;
+
+        fn suggest(&self, input: &str) -> Vec<Suggestion<u8>> {
+            self.suggest_at(input, input.chars().count()).1
+        }
+
+        fn suggest_at(&self, input: &str, cursor: usize) -> (Range<usize>, Vec<Suggestion<u8>>) {
+            let chars: Vec<char> = input.chars().collect();
+            let start = chars[..cursor]
+                .iter()
+                .rposition(|c| c.is_whitespace())
+                .map_or(0, |i| i + 1);
+            let word: String = chars[start..cursor].iter().collect();
+
+            let suggestions = [("--release", 1), ("--verbose", 2)]
+                .into_iter()
+                .filter(|(flag, _)| flag.starts_with(&word))
+                .map(|(flag, value)| Suggestion::new(flag).value(value))
+                .collect();
+            (start..cursor, suggestions)
+        }
+    }
+
+    #[test]
+    fn token() {
+        let mut autocomplete = Autocomplete::new(Flags);
+        autocomplete.on(&Key::Char('r'), "build --r", 9);
+        assert_eq!(autocomplete.token_range("build --r"), 6..9);
+
+        // The range follows the token while it's edited.
+        assert_eq!(autocomplete.token_range("build --rel"), 6..11);
+        assert_eq!(autocomplete.token("build --rel"), "--rel");
+
+        // Only the token is compared with the suggestions.
+        assert!(autocomplete.is_known("build --release"));
+        assert!(!autocomplete.is_known("build --rel"));
+        assert!(!autocomplete.is_known("build --release --verbose"));
+
+        // The value is kept while the token is the accepted suggestion.
+        autocomplete.on(&Key::ArrowDown, "build --r", 9);
+        let state = autocomplete.on(&Key::Enter, "build --r", 9);
+        assert!(
+            matches!(state, Some(State::Submit(c)) if c.range == (6..9) && c.text == "--release")
+        );
+        assert_eq!(autocomplete.take_value("build --release"), Some(1));
+
+        autocomplete.on(&Key::Enter, "build --r", 9);
+        assert_eq!(autocomplete.take_value("build --releases"), None);
+    }
+
+    #[test]
+    fn known_while_pending() {
+        let (source, called, release) = gated();
+        let mut autocomplete = Autocomplete::new_async(source, Duration::from_millis(1));
+
+        write(&mut autocomplete, "a");
+        assert_eq!(called.recv().unwrap(), "a");
+        release.send(()).unwrap();
+        wait(&mut autocomplete);
+
+        // The latest results are checked without calling the blocked source.
+        write(&mut autocomplete, "a1");
+        assert_eq!(called.recv().unwrap(), "a1");
+        assert!(autocomplete.is_pending());
+        assert!(autocomplete.is_known("a1"));
+        assert!(!autocomplete.is_known("a3"));
+        assert!(called.try_recv().is_err());
+        release.send(()).unwrap();
+    }
+
+    #[test]
+    fn window() {
+        const ITEMS: &[&str] = &["a0", "a1", "a2", "a3", "a4", "a5", "a6", "a7", "a8", "a9"];
+        let mut autocomplete = Autocomplete::new(words(ITEMS));
+        autocomplete.max_rows(3);
+        write(&mut autocomplete, "a");
+
+        let window = |autocomplete: &Autocomplete<Untyped>| {
+            let window = autocomplete.window();
+            (window.start, window.height)
+        };
+        let press = |autocomplete: &mut Autocomplete<Untyped>, key: Key, times: usize| {
+            for _ in 0..times {
+                autocomplete.on(&key, "a", 1);
+            }
+        };
+        assert_eq!(window(&autocomplete), (0, 3));
+
+        // The window follows the cursor at the bottom edge.
+        press(&mut autocomplete, Key::ArrowDown, 3);
+        assert_eq!(
+            (autocomplete.cursor, window(&autocomplete)),
+            (Some(2), (0, 3))
+        );
+        press(&mut autocomplete, Key::ArrowDown, 1);
+        assert_eq!(
+            (autocomplete.cursor, window(&autocomplete)),
+            (Some(3), (1, 3))
+        );
+        press(&mut autocomplete, Key::ArrowDown, 6);
+        assert_eq!(
+            (autocomplete.cursor, window(&autocomplete)),
+            (Some(9), (7, 3))
+        );
+
+        // Wrapping around to the top.
+        press(&mut autocomplete, Key::ArrowDown, 1);
+        assert_eq!(
+            (autocomplete.cursor, window(&autocomplete)),
+            (Some(0), (0, 3))
+        );
+
+        // The window follows the cursor at the top edge.
+        press(&mut autocomplete, Key::ArrowDown, 5);
+        press(&mut autocomplete, Key::ArrowUp, 3);
+        assert_eq!(
+            (autocomplete.cursor, window(&autocomplete)),
+            (Some(2), (2, 3))
+        );
+
+        // The window is capped when the list shrinks below the cursor.
+        press(&mut autocomplete, Key::ArrowDown, 7);
+        write(&mut autocomplete, "a1");
+        assert_eq!(window(&autocomplete), (0, 1));
+        write(&mut autocomplete, "a");
+        assert_eq!(window(&autocomplete), (7, 3));
+    }
+
+    #[test]
+    fn highlighting() {
+        use crate::ThemeState;
+
+        /// Shows the matched characters instead of highlighting them.
+        struct Matches;
+        impl Theme for Matches {
+            fn format_autocomplete_suggestion(
+                &self,
+                _: &ThemeState,
+                _: bool,
+                label: &str,
+                _: &str,
+                matches: &[usize],
+            ) -> String {
+                format!("{label} {matches:?}\n")
+            }
+        }
+
+        let mut autocomplete = Autocomplete::new(vec!["rust".to_string(), "ruby".to_string()]);
+        write(&mut autocomplete, "ru");
+
+        // The characters matched by the source are highlighted by the theme.
+        let rendered = autocomplete.render_with(&Matches, &State::<()>::Active);
+        assert_eq!(rendered, "rust [0, 1]\nruby [0, 1]\n");
 
-        empty_line.chain(items).collect()
+        write(&mut autocomplete, "rub");
+        let rendered = autocomplete.render_with(&Matches, &State::<()>::Error(String::new()));
+        assert_eq!(rendered, "ruby [0, 1, 2]\nrust [0, 1]\n");
     }
 }
@@ -0,0 +1,463 @@
+//! Interactive fallbacks for [`clap`](https://docs.rs/clap) arguments
+//! (requires the `clap` feature).
+//!
+//! Required arguments of the command and the invoked subcommands that were not
+//! supplied on the command line are prompted for instead of failing with
+//! a usage error:
+//!
+//! * [`Select`] for arguments with possible values (e.g. `ValueEnum`),
+//!   [`MultiSelect`] if multiple values are accepted.
+//! * [`Confirm`] for flags.
+//! * [`Input`] for the rest, validated by the argument's value parser.
+//!
+//! The help text of the argument is shown as a hint.
+//!
+//! ```no_run
+//! use clap::Parser;
+//!
+//! #[derive(Parser)]
+//! struct Cli {
+//!     /// Name of the project
+//!     #[arg(long)]
+//!     name: String,
+//! }
+//!
+//! # fn test() -> std::io::Result<()> {
+//! // `--name` is prompted for if it's missing.
+//! let cli: Cli = cliclack::clap::parse()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::OsString;
+use std::io;
+
+use ::clap::{parser::ValueSource, Arg, ArgAction, ArgMatches, Command, Id, Parser};
+
+use crate::theme::{ThemeState, THEME};
+use crate::{Confirm, Input, MultiSelect, Select};
+
+/// Parses the process arguments into `P`, prompting for the missing required ones.
+///
+/// Like [`Parser::parse`], exits the process on a command line error
+/// (including `--help` and `--version`).
+pub fn parse<P: Parser>() -> io::Result<P> {
+    parse_from(std::env::args_os())
+}
+
+/// Parses the given arguments into `P`, prompting for the missing required ones.
+///
+/// See [`parse`].
+pub fn parse_from<P, I, T>(args: I) -> io::Result<P>
+where
+    P: Parser,
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let matches = get_matches_from(P::command(), args)?;
+    Ok(P::from_arg_matches(&matches).unwrap_or_else(|err| err.exit()))
+}
+
+/// Parses the process arguments with the given command, prompting for the
+/// missing required ones.
+///
+/// Like [`Command::get_matches`], exits the process on a command line error.
+pub fn get_matches(cmd: Command) -> io::Result<ArgMatches> {
+    get_matches_from(cmd, std::env::args_os())
+}
+
+/// Parses the given arguments with the given command, prompting for the
+/// missing required ones.
+///
+/// See [`get_matches`].
+pub fn get_matches_from<I, T>(cmd: Command, args: I) -> io::Result<ArgMatches>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let args = args.into_iter().map(Into::into).collect();
+    let (cmd, args) = complete(cmd, args, prompt)?;
+
+    Ok(cmd
+        .try_get_matches_from(args)
+        .unwrap_or_else(|err| err.exit()))
+}
+
+/// Adds the values of the missing required arguments of the command and the
+/// invoked subcommands to the command line, asking the `prompt` for them.
+///
+/// Returns the command allowing the added values and the completed arguments.
+fn complete<F>(
+    cmd: Command,
+    mut args: Vec<OsString>,
+    mut prompt: F,
+) -> io::Result<(Command, Vec<OsString>)>
+where
+    F: FnMut(&Arg) -> io::Result<Vec<OsString>>,
+{
+    // Parse once with all arguments being optional to find out what's missing.
+    let matches = relaxed(cmd.clone())
+        .try_get_matches_from(&args)
+        .unwrap_or_else(|err| err.exit());
+
+    // The command and the invoked subcommands with positions of their names.
+    let mut commands = vec![(&cmd, &matches, 0)];
+    loop {
+        let &(parent, matches, position) = commands.last().unwrap();
+        let Some((name, sub_matches)) = matches.subcommand() else {
+            break;
+        };
+        let Some(sub) = parent.find_subcommand(name) else {
+            break;
+        };
+        let position = args
+            .iter()
+            .skip(position + 1)
+            .position(|arg| is_invoked_as(sub, arg))
+            .map_or(args.len(), |i| position + 1 + i);
+        commands.push((sub, sub_matches, position));
+    }
+
+    let mut insertions = vec![];
+    let mut hyphen_values = vec![];
+
+    for (i, &(cmd, matches, position)) in commands.iter().enumerate() {
+        let mut options = vec![];
+        let mut positionals = vec![];
+
+        for arg in cmd.get_arguments() {
+            if !arg.is_required_set() || !is_missing(matches, arg) {
+                continue;
+            }
+
+            let values = prompt(arg)?;
+
+            if arg.is_positional() {
+                positionals.extend(values);
+                // Prompted values are never flags, even starting with `-`.
+                let path: Vec<String> = commands[1..=i]
+                    .iter()
+                    .map(|c| c.0.get_name().to_string())
+                    .collect();
+                hyphen_values.push((path, arg.get_id().clone()));
+            } else {
+                options.extend(values);
+            }
+        }
+
+        // Options go right after the command name, positional values go
+        // before a subcommand (if any) to keep their order.
+        let start = (position + 1).min(args.len());
+        let end = commands.get(i + 1).map_or(args.len(), |c| c.2);
+        insertions.push((start, options, end, positionals));
+    }
+
+    // Splicing from the end keeps positions of the preceding commands.
+    for (start, options, end, positionals) in insertions.into_iter().rev() {
+        args.splice(end..end, positionals);
+        args.splice(start..start, options);
+    }
+
+    let cmd = hyphen_values.iter().fold(cmd.clone(), |cmd, (path, id)| {
+        allow_hyphen_values(cmd, path, id)
+    });
+
+    Ok((cmd, args))
+}
+
+/// Makes all arguments of the command and its subcommands optional.
+fn relaxed(cmd: Command) -> Command {
+    let subcommands: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+
+    subcommands.iter().fold(
+        cmd.arg_required_else_help(false)
+            .mut_args(|arg| arg.required(false)),
+        |cmd, name| cmd.mut_subcommand(name, relaxed),
+    )
+}
+
+/// Checks if the command line argument is the name or an alias of the subcommand.
+fn is_invoked_as(sub: &Command, arg: &OsString) -> bool {
+    *arg == *sub.get_name() || sub.get_all_aliases().any(|alias| *arg == *alias)
+}
+
+/// Allows values starting with `-` for the argument of the (sub)command at the path.
+fn allow_hyphen_values(cmd: Command, path: &[String], id: &Id) -> Command {
+    match path.split_first() {
+        None => cmd.mut_arg(id, |arg| arg.allow_hyphen_values(true)),
+        Some((name, path)) => cmd.mut_subcommand(name, |sub| allow_hyphen_values(sub, path, id)),
+    }
+}
+
+/// Checks if the argument is not supplied neither on the command line nor
+/// with an environment variable.
+fn is_missing(matches: &ArgMatches, arg: &Arg) -> bool {
+    match matches.value_source(arg.get_id().as_str()) {
+        Some(ValueSource::CommandLine) | Some(ValueSource::EnvVariable) => false,
+        // Flags always have a default value.
+        Some(ValueSource::DefaultValue) => is_flag(arg),
+        _ => true,
+    }
+}
+
+fn is_flag(arg: &Arg) -> bool {
+    matches!(arg.get_action(), ArgAction::SetTrue | ArgAction::SetFalse)
+}
+
+/// Prompts for an argument returning command line arguments to be added.
+fn prompt(arg: &Arg) -> io::Result<Vec<OsString>> {
+    let name = prompt_text(arg);
+
+    if is_flag(arg) {
+        let set = Confirm::new(name).interact()?;
+        let flag = match arg.get_action() {
+            ArgAction::SetTrue => set,
+            _ => !set,
+        };
+        return Ok(if flag {
+            vec![option(arg, None)]
+        } else {
+            vec![]
+        });
+    }
+
+    let possible_values: Vec<_> = arg
+        .get_possible_values()
+        .into_iter()
+        .filter(|pv| !pv.is_hide_set())
+        .collect();
+    let default = arg
+        .get_default_values()
+        .first()
+        .map(|v| v.to_string_lossy().to_string());
+
+    let values = if !possible_values.is_empty() {
+        let items: Vec<_> = possible_values
+            .iter()
+            .map(|pv| {
+                let hint = pv.get_help().map(ToString::to_string).unwrap_or_default();
+                (pv.get_name().to_string(), pv.get_name().to_string(), hint)
+            })
+            .collect();
+
+        if matches!(arg.get_action(), ArgAction::Append) {
+            MultiSelect::new(name).items(&items).interact()?
+        } else {
+            let mut select = Select::new(name).items(&items);
+            if let Some(default) = default {
+                select = select.initial_value(default);
+            }
+            vec![select.interact()?]
+        }
+    } else {
+        let mut input = Input::new(name);
+        if let Some(default) = &default {
+            input = input.default_input(default);
+        }
+
+        let validator = validator(arg);
+        vec![input
+            .validate(move |value: &String| {
+                validator
+                    .clone()
+                    .try_get_matches_from([value])
+                    .map(|_| ())
+                    .map_err(|err| error_message(&err))
+            })
+            .interact()?]
+    };
+
+    Ok(values
+        .into_iter()
+        .map(|value| match arg.is_positional() {
+            true => value.into(),
+            false => option(arg, Some(&value)),
+        })
+        .collect())
+}
+
+/// The prompt text made from the value name or from the argument identifier,
+/// followed by the help text as a hint.
+fn prompt_text(arg: &Arg) -> String {
+    let hint = arg.get_help().map(|help| {
+        let style = THEME.read().unwrap().placeholder_style(&ThemeState::Active);
+        format!(" {}", style.apply_to(format!("({help})")))
+    });
+
+    let name = arg
+        .get_value_names()
+        .and_then(|names| names.first().map(ToString::to_string))
+        .unwrap_or_else(|| arg.get_id().to_string())
+        .to_lowercase()
+        .replace(['_', '-'], " ");
+
+    let mut chars = name.chars();
+    let name = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    };
+
+    name + &hint.unwrap_or_default()
+}
+
+/// Builds an option (`--name=value` or `-n=value`) to be added to the command line.
+fn option(arg: &Arg, value: Option<&str>) -> OsString {
+    let option = match (arg.get_long(), arg.get_short()) {
+        (Some(long), _) => format!("--{long}"),
+        (None, Some(short)) => format!("-{short}"),
+        (None, None) => unreachable!("an option without a name"),
+    };
+
+    match value {
+        Some(value) => format!("{option}={value}"),
+        None => option,
+    }
+    .into()
+}
+
+/// Builds a command validating a single value with the argument's value parser.
+fn validator(arg: &Arg) -> Command {
+    let mut value = Arg::new(arg.get_id().clone())
+        .value_parser(arg.get_value_parser().clone())
+        .allow_hyphen_values(true)
+        .required(true);
+    if let Some(names) = arg.get_value_names() {
+        value = value.value_names(names.to_vec());
+    }
+
+    Command::new("value")
+        .no_binary_name(true)
+        .disable_help_flag(true)
+        .arg(value)
+}
+
+/// Extracts a short message from a rendered error like
+/// `error: invalid value 'x' for '<PORT>': invalid digit found in string`.
+fn error_message(err: &::clap::Error) -> String {
+    let message = err.to_string();
+    let line = message.lines().next().unwrap_or_default();
+    line.strip_prefix("error: ").unwrap_or(line).to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn command() -> Command {
+        Command::new("app")
+            .arg(Arg::new("port").long("port").value_parser(clap_port()))
+            .arg(Arg::new("name").required(true))
+            .arg(Arg::new("verbose").short('v').action(ArgAction::SetTrue))
+    }
+
+    fn clap_port() -> ::clap::builder::RangedI64ValueParser<u16> {
+        ::clap::value_parser!(u16).range(1..)
+    }
+
+    #[test]
+    fn missing_arguments() {
+        let cmd = command().mut_arg("port", |arg| arg.required(true));
+        let matches = cmd
+            .clone()
+            .mut_args(|arg| arg.required(false))
+            .get_matches_from(["app", "-v"]);
+
+        let port = cmd.get_arguments().find(|a| a.get_id() == "port").unwrap();
+        let name = cmd.get_arguments().find(|a| a.get_id() == "name").unwrap();
+        let verbose = cmd
+            .get_arguments()
+            .find(|a| a.get_id() == "verbose")
+            .unwrap();
+
+        assert!(is_missing(&matches, port));
+        assert!(is_missing(&matches, name));
+        assert!(!is_missing(&matches, verbose));
+    }
+
+    #[test]
+    fn value_parser_validation() {
+        let cmd = command();
+        let port = cmd.get_arguments().find(|a| a.get_id() == "port").unwrap();
+        let validator = validator(port);
+
+        assert!(validator.clone().try_get_matches_from(["8080"]).is_ok());
+
+        let err = validator.try_get_matches_from(["0"]).unwrap_err();
+        assert!(error_message(&err).starts_with("invalid value '0'"));
+    }
+
+    #[test]
+    fn subcommand_arguments() {
+        let cmd = command().subcommand(
+            Command::new("build")
+                .alias("b")
+                .arg(Arg::new("target").required(true))
+                .arg(Arg::new("profile").long("profile").required(true)),
+        );
+        let mut prompted = vec![];
+
+        let (cmd, args) = complete(cmd, vec!["app".into(), "b".into()], |arg| {
+            prompted.push(arg.get_id().to_string());
+            Ok(match arg.get_id().as_str() {
+                "name" => vec!["my-app".into()],
+                // A value which looks like a flag.
+                "target" => vec!["-t".into()],
+                _ => vec![option(arg, Some("dev"))],
+            })
+        })
+        .unwrap();
+
+        assert_eq!(prompted, ["name", "target", "profile"]);
+        assert_eq!(args, ["app", "my-app", "b", "--profile=dev", "-t"]);
+
+        let matches = cmd.try_get_matches_from(args).unwrap();
+        assert_eq!(matches.get_one::<String>("name").unwrap(), "my-app");
+        let (name, build) = matches.subcommand().unwrap();
+        assert_eq!(name, "build");
+        assert_eq!(build.get_one::<String>("target").unwrap(), "-t");
+        assert_eq!(build.get_one::<String>("profile").unwrap(), "dev");
+    }
+
+    #[test]
+    fn supplied_arguments() {
+        let cmd = command().subcommand(Command::new("run").arg(Arg::new("bin").required(true)));
+
+        let (_, args) = complete(
+            cmd,
+            vec!["app", "x", "run", "main"]
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            |arg| panic!("unexpected prompt for {}", arg.get_id()),
+        )
+        .unwrap();
+
+        assert_eq!(args, ["app", "x", "run", "main"]);
+    }
+
+    #[test]
+    fn help_hint() {
+        let arg = Arg::new("port").help("A port of the dev server");
+        assert_eq!(
+            console::strip_ansi_codes(&prompt_text(&arg)),
+            "Port (A port of the dev server)"
+        );
+    }
+
+    #[test]
+    fn options() {
+        let cmd = command();
+        let port = cmd.get_arguments().find(|a| a.get_id() == "port").unwrap();
+        let verbose = cmd
+            .get_arguments()
+            .find(|a| a.get_id() == "verbose")
+            .unwrap();
+
+        assert_eq!(option(port, Some("80")), "--port=80");
+        assert_eq!(option(verbose, None), "-v");
+        assert_eq!(prompt_text(port), "Port");
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt::Display;
+use std::io;
+
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
+use console::Key;
+
+use crate::{
+    messages::MESSAGES,
+    prompt::{
+        cursor::StringCursor,
+        interaction::{Event, PromptInteraction, State},
+    },
+    theme::THEME,
+};
+
+type DisabledCallback = Box<dyn Fn(&NaiveDate) -> bool>;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Returns the number of days in the month of the date.
+fn days_in_month(date: NaiveDate) -> u32 {
+    date.with_day(1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+/// Returns the number of empty calendar cells before the first day of
+/// the month, if the week starts with `first_weekday`.
+fn weekday_offset(date: NaiveDate, first_weekday: Weekday) -> u32 {
+    let first = date.with_day(1).unwrap_or(date);
+    (first.weekday().num_days_from_monday() + 7 - first_weekday.num_days_from_monday()) % 7
+}
+
+/// A prompt that picks a date from a month calendar (requires the `chrono`
+/// feature).
+///
+/// * `Left`/`Right` to move by day.
+/// * `Up`/`Down` to move by week.
+/// * `PageUp`/`PageDown` to move by month.
+/// * Type a date in the ISO format (`YYYY-MM-DD`) to jump to it, typing
+///   replaces the selected date.
+/// * `Enter` to submit.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use cliclack::DatePicker;
+///
+/// # fn test() -> std::io::Result<()> {
+/// let date: NaiveDate = DatePicker::new("Maintenance window")
+///     .min(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+///     .disabled(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// # test().ok(); // Ignoring I/O runtime errors.
+/// ```
+pub struct DatePicker {
+    prompt: String,
+    input: StringCursor,
+    /// Whether the input is the selected date, which typing replaces.
+    prefilled: bool,
+    selected: NaiveDate,
+    initial_value: Option<NaiveDate>,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    disabled: Option<DisabledCallback>,
+    first_weekday: Weekday,
+}
+
+impl DatePicker {
+    /// Creates a new date prompt.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            input: StringCursor::default(),
+            prefilled: false,
+            selected: NaiveDate::default(),
+            initial_value: None,
+            min: None,
+            max: None,
+            disabled: None,
+            first_weekday: Weekday::Mon,
+        }
+    }
+
+    /// Sets the initially selected date. Default: today.
+    pub fn initial_value(mut self, date: NaiveDate) -> Self {
+        self.initial_value = Some(date);
+        self
+    }
+
+    /// Sets the earliest date which can be picked.
+    pub fn min(mut self, date: NaiveDate) -> Self {
+        self.min = Some(date);
+        self
+    }
+
+    /// Sets the latest date which can be picked.
+    pub fn max(mut self, date: NaiveDate) -> Self {
+        self.max = Some(date);
+        self
+    }
+
+    /// Sets a callback disabling dates, e.g. weekends or holidays.
+    pub fn disabled<F>(mut self, disabled: F) -> Self
+    where
+        F: Fn(&NaiveDate) -> bool + 'static,
+    {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Sets the first day of the week in the calendar. Default: Monday.
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Starts the prompt interaction.
+    pub fn interact(&mut self) -> io::Result<NaiveDate> {
+        let date = self
+            .initial_value
+            .unwrap_or_else(|| Local::now().date_naive());
+        self.select(date);
+        <Self as PromptInteraction<NaiveDate>>::interact(self)
+    }
+
+    /// Selects the date within the bounds updating the input text.
+    fn select(&mut self, mut date: NaiveDate) {
+        if let Some(min) = self.min {
+            date = date.max(min);
+        }
+        if let Some(max) = self.max {
+            date = date.min(max);
+        }
+
+        self.selected = date;
+        self.input.clear();
+        self.input.extend(&date.format(DATE_FORMAT).to_string());
+        self.input.move_end();
+        self.prefilled = true;
+    }
+
+    fn is_enabled(&self, date: &NaiveDate) -> bool {
+        self.min.is_none_or(|min| *date >= min)
+            && self.max.is_none_or(|max| *date <= max)
+            && !self
+                .disabled
+                .as_ref()
+                .is_some_and(|disabled| disabled(date))
+    }
+}
+
+impl PromptInteraction<NaiveDate> for DatePicker {
+    fn input(&mut self) -> Option<&mut StringCursor> {
+        Some(&mut self.input)
+    }
+
+    fn on(&mut self, event: &Event) -> State<NaiveDate> {
+        let Event::Key(key) = event;
+        let date = self.selected;
+
+        let moved = match key {
+            Key::ArrowLeft => date.checked_sub_days(Days::new(1)),
+            Key::ArrowRight => date.checked_add_days(Days::new(1)),
+            Key::ArrowUp => date.checked_sub_days(Days::new(7)),
+            Key::ArrowDown => date.checked_add_days(Days::new(7)),
+            Key::PageUp => date.checked_sub_months(Months::new(1)),
+            Key::PageDown => date.checked_add_months(Months::new(1)),
+            Key::Enter => {
+                let messages = MESSAGES.read().unwrap();
+                return match NaiveDate::parse_from_str(&self.input.to_string(), DATE_FORMAT) {
+                    Ok(date) if self.is_enabled(&date) => State::Submit(date),
+                    Ok(_) => State::Error(messages.date_not_available()),
+                    Err(_) => State::Error(messages.invalid_value_format()),
+                };
+            }
+            _ => {
+                // The first typed character replaces the selected date.
+                if let Key::Char(chr) = key {
+                    if self.prefilled && !chr.is_ascii_control() {
+                        self.input.clear();
+                        self.input.insert(*chr);
+                    }
+                }
+                self.prefilled = false;
+
+                // Jump to the typed date, even out of bounds to show it's disabled.
+                if let Ok(date) = NaiveDate::parse_from_str(&self.input.to_string(), DATE_FORMAT) {
+                    self.selected = date;
+                }
+                return State::Active;
+            }
+        };
+
+        if let Some(date) = moved {
+            self.select(date);
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<NaiveDate>) -> String {
+        let theme = THEME.read().unwrap();
+        let messages = MESSAGES.read().unwrap();
+
+        let first = self.selected.with_day(1).unwrap_or(self.selected);
+        let offset = weekday_offset(first, self.first_weekday);
+
+        let title = format!("{} {}", messages.month_name(first.month()), first.year());
+        let weekdays: Vec<String> = (0..7)
+            .map(|i| messages.weekday_short((self.first_weekday.num_days_from_monday() + i) % 7))
+            .collect();
+
+        let mut cells = vec![String::new(); offset as usize];
+        cells.extend((1..=days_in_month(first)).map(|day| {
+            let date = first.with_day(day).unwrap_or(first);
+            theme.format_calendar_day(
+                &state.into(),
+                day,
+                date == self.selected,
+                self.is_enabled(&date),
+            )
+        }));
+        let weeks: Vec<Vec<String>> = cells.chunks(7).map(<[String]>::to_vec).collect();
+
+        let line1 = theme.format_header(&state.into(), &self.prompt);
+        let line2 = theme.format_input(&state.into(), &self.input);
+        let line3 = theme.format_calendar(&state.into(), &title, &weekdays, &weeks);
+        let line4 = theme.format_footer(&state.into());
+
+        line1 + &line2 + &line3 + &line4
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn press(picker: &mut DatePicker, key: Key) -> State<NaiveDate> {
+        if let Key::Char(chr) = key {
+            picker.input.insert(chr);
+        }
+        picker.on(&Event::Key(key))
+    }
+
+    #[test]
+    fn month_days() {
+        assert_eq!(days_in_month(date(2025, 1, 15)), 31);
+        assert_eq!(days_in_month(date(2025, 4, 30)), 30);
+        assert_eq!(days_in_month(date(2025, 12, 1)), 31);
+        // Leap years.
+        assert_eq!(days_in_month(date(2024, 2, 1)), 29);
+        assert_eq!(days_in_month(date(2025, 2, 1)), 28);
+        assert_eq!(days_in_month(date(2000, 2, 1)), 29);
+        assert_eq!(days_in_month(date(1900, 2, 1)), 28);
+    }
+
+    #[test]
+    fn weekday_offsets() {
+        // June 1, 2025 is Sunday.
+        assert_eq!(weekday_offset(date(2025, 6, 15), Weekday::Mon), 6);
+        assert_eq!(weekday_offset(date(2025, 6, 15), Weekday::Sun), 0);
+        // September 1, 2025 is Monday.
+        assert_eq!(weekday_offset(date(2025, 9, 1), Weekday::Mon), 0);
+        assert_eq!(weekday_offset(date(2025, 9, 1), Weekday::Sun), 1);
+        assert_eq!(weekday_offset(date(2025, 9, 1), Weekday::Tue), 6);
+    }
+
+    #[test]
+    fn typing_replaces_date() {
+        let mut picker = DatePicker::new("Date");
+        picker.select(date(2025, 6, 15));
+
+        for chr in "2024-02-29".chars() {
+            press(&mut picker, Key::Char(chr));
+        }
+        assert_eq!(picker.input.to_string(), "2024-02-29");
+        assert_eq!(picker.selected, date(2024, 2, 29));
+        assert!(
+            matches!(press(&mut picker, Key::Enter), State::Submit(d) if d == date(2024, 2, 29))
+        );
+
+        // Moving fills the input with the date again.
+        press(&mut picker, Key::ArrowRight);
+        assert_eq!(picker.input.to_string(), "2024-03-01");
+    }
+
+    #[test]
+    fn bounds() {
+        let mut picker = DatePicker::new("Date")
+            .min(date(2025, 1, 1))
+            .disabled(|date| date.weekday() == Weekday::Sat);
+        picker.select(date(2024, 12, 31));
+        assert_eq!(picker.selected, date(2025, 1, 1));
+
+        press(&mut picker, Key::ArrowLeft);
+        assert_eq!(picker.selected, date(2025, 1, 1));
+
+        // January 4, 2025 is Saturday.
+        for _ in 0..3 {
+            press(&mut picker, Key::ArrowRight);
+        }
+        assert!(matches!(press(&mut picker, Key::Enter), State::Error(_)));
+    }
+}
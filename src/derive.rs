@@ -0,0 +1,179 @@
+use std::fmt::Display;
+use std::io;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::{Input, Select};
+
+/// A type which is constructed interactively by a sequence of prompts.
+///
+/// Normally, it's implemented with `#[derive(Prompt)]` (requires the `derive`
+/// feature). Each field is prompted in the order of declaration, and the
+/// prompt is chosen by the field type:
+///
+/// * `bool` becomes [`Confirm`](crate::Confirm).
+/// * An enum deriving `Prompt` becomes [`Select`].
+/// * `Vec<T>` of such an enum becomes [`MultiSelect`](crate::MultiSelect).
+/// * `Option<T>` becomes a non-required [`Input`] (empty input is `None`).
+/// * Any other [`FromStr`] type becomes [`Input`].
+///
+/// Fields are customized with the `#[prompt(...)]` attribute:
+///
+/// * `"text"`: the prompt text (by default, made from the field name).
+/// * `placeholder = "..."`: see [`Input::placeholder`].
+/// * `default = "..."`: see [`Input::default_input`], or `default = true`
+///   for a `bool` field, or a label of a default choice.
+/// * `validate = ...`: a validator, see [`Input::validate`].
+/// * `choices = ["a", "b"]`: selection from the given strings.
+/// * `password`: [`Password`](crate::Password) prompt.
+/// * `skip`: don't prompt, use [`Default`].
+///
+/// An option not supported by the chosen prompt is a compile error, e.g.
+/// `placeholder` of a `choices` field, or `default` of a `Vec<T>` field.
+/// So is a `default` which isn't one of the `choices`, while an unknown
+/// default label of an enum field fails with [`io::ErrorKind::InvalidInput`].
+///
+/// Enum variants accept `#[prompt("label", hint = "...")]`, and the enum itself
+/// must be `Clone + Eq`.
+///
+/// ```no_run
+/// use cliclack::Prompt;
+///
+/// #[derive(Prompt, Clone, PartialEq, Eq)]
+/// enum Language {
+///     #[prompt("Rust", hint = "recommended")]
+///     Rust,
+///     #[prompt("TypeScript")]
+///     TypeScript,
+/// }
+///
+/// #[derive(Prompt)]
+/// struct Config {
+///     #[prompt("Project name", placeholder = "my-app")]
+///     name: String,
+///     #[prompt("Port", default = "8080")]
+///     port: u16,
+///     language: Language,
+///     #[prompt("Install dependencies?", default = true)]
+///     install: bool,
+///     description: Option<String>,
+/// }
+///
+/// # fn test() -> std::io::Result<()> {
+/// let config = Config::interact()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Prompt: Sized {
+    /// Prompts the user and returns the constructed value.
+    fn interact() -> io::Result<Self>;
+}
+
+/// A fixed list of values to choose from, see [`Prompt`].
+///
+/// Implemented with `#[derive(Prompt)]` for enums with unit variants.
+pub trait Choices: Sized + Clone + Eq {
+    /// Returns the list of `(value, label, hint)` items.
+    fn choices() -> Vec<(Self, String, String)>;
+}
+
+/// The code used by `#[derive(Prompt)]`, not a public API.
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    /// Parses a submitted value into the field type.
+    pub fn parse<T>(value: &str) -> io::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        value
+            .parse()
+            .map_err(|err: T::Err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+
+    /// A field of the type `T`, which prompt depends on traits implemented by `T`.
+    ///
+    /// The prompt is picked by auto-referencing method resolution:
+    /// `(&&Field::<T>::new(..)).interact(..)` calls [`SelectField`] if `T`
+    /// implements [`Choices`], otherwise, it falls back to [`InputField`].
+    pub struct Field<T> {
+        prompt: &'static str,
+        default: Option<&'static str>,
+        marker: PhantomData<T>,
+    }
+
+    impl<T> Field<T> {
+        pub fn new(prompt: &'static str, default: Option<&'static str>) -> Self {
+            Self {
+                prompt,
+                default,
+                marker: PhantomData,
+            }
+        }
+    }
+
+    pub trait SelectField<T> {
+        fn interact(&self, configure: &dyn Fn(Input) -> Input) -> io::Result<T>;
+    }
+
+    impl<T: Choices> SelectField<T> for &&Field<T> {
+        fn interact(&self, _configure: &dyn Fn(Input) -> Input) -> io::Result<T> {
+            let choices = T::choices();
+            let mut select = Select::new(self.prompt);
+
+            if let Some(default) = self.default {
+                let Some((value, _, _)) = choices.iter().find(|(_, label, _)| label == default)
+                else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("unknown default choice: \"{default}\""),
+                    ));
+                };
+                select = select.initial_value(value.clone());
+            }
+
+            select.items(&choices).interact()
+        }
+    }
+
+    pub trait InputField<T> {
+        fn interact(&self, configure: &dyn Fn(Input) -> Input) -> io::Result<T>;
+    }
+
+    impl<T> InputField<T> for &Field<T>
+    where
+        T: FromStr + 'static,
+        T::Err: Display,
+    {
+        fn interact(&self, configure: &dyn Fn(Input) -> Input) -> io::Result<T> {
+            configure(Input::new(self.prompt)).interact()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::__private::*;
+    use super::*;
+
+    #[derive(Clone, PartialEq, Eq)]
+    struct Language;
+
+    impl Choices for Language {
+        fn choices() -> Vec<(Self, String, String)> {
+            vec![(Language, "Rust".into(), String::new())]
+        }
+    }
+
+    #[test]
+    fn unknown_default_choice() {
+        // Rejected before prompting, since the enum variants aren't known to
+        // the derive macro of the struct.
+        let field = Field::<Language>::new("Language", Some("Go"));
+        let err = (&&field).interact(&|input| input).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "unknown default choice: \"Go\"");
+    }
+}
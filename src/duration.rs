@@ -0,0 +1,210 @@
+use std::fmt::Display;
+use std::io;
+use std::time::Duration;
+
+use console::Key;
+
+use crate::{
+    messages::MESSAGES,
+    prompt::{
+        cursor::StringCursor,
+        interaction::{Event, PromptInteraction, State},
+    },
+    theme::THEME,
+};
+
+/// Parses a humanized duration like `1h30m`, `90s`, `2d 4h` or `250ms`.
+///
+/// Supported units: `d`, `h`, `m`, `s`, `ms`. A number without a unit is
+/// seconds.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut total = Duration::ZERO;
+    let mut chars = text.chars().filter(|c| !c.is_whitespace()).peekable();
+
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            number.push(digit);
+        }
+
+        let mut unit = String::new();
+        while let Some(letter) = chars.next_if(char::is_ascii_alphabetic) {
+            unit.push(letter);
+        }
+
+        let number: u64 = number.parse().ok()?;
+        let part = match unit.as_str() {
+            "d" => Duration::from_secs(number.checked_mul(24 * 60 * 60)?),
+            "h" => Duration::from_secs(number.checked_mul(60 * 60)?),
+            "m" => Duration::from_secs(number.checked_mul(60)?),
+            "s" | "" => Duration::from_secs(number),
+            "ms" => Duration::from_millis(number),
+            _ => return None,
+        };
+
+        total = total.checked_add(part)?;
+    }
+
+    Some(total)
+}
+
+/// Formats the duration in the compact form accepted by [`parse_duration`],
+/// e.g. `1h30m`.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let parts = [
+        (secs / 86400, "d"),
+        (secs / 3600 % 24, "h"),
+        (secs / 60 % 60, "m"),
+        (secs % 60, "s"),
+        (u64::from(duration.subsec_millis()), "ms"),
+    ];
+
+    let compact: String = parts
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+
+    match compact.is_empty() {
+        true => "0s".into(),
+        false => compact,
+    }
+}
+
+/// A prompt that accepts a humanized duration like `1h30m` or `90s`.
+///
+/// Supported units: `d`, `h`, `m`, `s`, `ms`. A number without a unit is
+/// seconds. The parsed interpretation is shown below the input.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use cliclack::DurationInput;
+///
+/// # fn test() -> std::io::Result<()> {
+/// let timeout: Duration = DurationInput::new("Timeout")
+///     .default_value(Duration::from_secs(30))
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// # test().ok(); // Ignoring I/O runtime errors.
+/// ```
+#[derive(Default)]
+pub struct DurationInput {
+    prompt: String,
+    input: StringCursor,
+    placeholder: StringCursor,
+    default: Option<Duration>,
+}
+
+impl DurationInput {
+    /// Creates a new duration prompt.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the placeholder (hint) text for the input.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder.extend(placeholder);
+        self
+    }
+
+    /// Sets the default value, which is returned if no value is supplied,
+    /// and also a hint (placeholder) if one is not already set.
+    pub fn default_value(mut self, value: Duration) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    /// Starts the prompt interaction.
+    pub fn interact(&mut self) -> io::Result<Duration> {
+        if self.placeholder.is_empty() {
+            if let Some(default) = self.default {
+                let messages = MESSAGES.read().unwrap();
+                self.placeholder
+                    .extend(&messages.default_value(&messages.duration(default)));
+            }
+        }
+        <Self as PromptInteraction<Duration>>::interact(self)
+    }
+}
+
+impl PromptInteraction<Duration> for DurationInput {
+    fn input(&mut self) -> Option<&mut StringCursor> {
+        Some(&mut self.input)
+    }
+
+    fn on(&mut self, event: &Event) -> State<Duration> {
+        let Event::Key(key) = event;
+        let messages = MESSAGES.read().unwrap();
+
+        if self.input.is_empty() {
+            return match (key, self.default) {
+                (Key::Enter, Some(default)) => {
+                    self.input.extend(&format_duration(default));
+                    State::Submit(default)
+                }
+                (Key::Enter, None) => State::Error(messages.input_required()),
+                _ => State::Active,
+            };
+        }
+
+        match (key, parse_duration(&self.input.to_string())) {
+            (Key::Enter, Some(duration)) => State::Submit(duration),
+            (_, Some(_)) => State::Active,
+            (_, None) => State::Error(messages.invalid_duration()),
+        }
+    }
+
+    fn render(&mut self, state: &State<Duration>) -> String {
+        let theme = THEME.read().unwrap();
+        let messages = MESSAGES.read().unwrap();
+
+        let hint = match parse_duration(&self.input.to_string()) {
+            Some(duration) => format!("= {}", messages.duration(duration)),
+            None => String::new(),
+        };
+
+        let line1 = theme.format_header(&state.into(), &self.prompt);
+        let line2 = if self.input.is_empty() {
+            theme.format_placeholder(&state.into(), &self.placeholder)
+        } else {
+            theme.format_input(&state.into(), &self.input)
+        };
+        let line3 = theme.format_footer_with_message(&state.into(), &hint);
+
+        line1 + &line2 + &line3
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn humanized_durations() {
+        assert_eq!(parse_duration("1h30m"), Some(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2d 4h"), Some(Duration::from_secs(187_200)));
+        assert_eq!(parse_duration("1s250ms"), Some(Duration::from_millis(1250)));
+        assert_eq!(parse_duration("1x"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(
+            format_duration(Duration::from_millis(5_400_250)),
+            "1h30m250ms"
+        );
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+    }
+}
@@ -0,0 +1,192 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use zeroize::Zeroize;
+
+/// Returns the editor command from `$VISUAL` or `$EDITOR`, or a platform default.
+fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|cmd| !cmd.trim().is_empty())
+        .unwrap_or_else(|| match cfg!(windows) {
+            true => "notepad".into(),
+            false => "vi".into(),
+        })
+}
+
+/// Splits the editor command into the program and its arguments.
+///
+/// The whole command is the program if it's an existing file (e.g. a path
+/// with spaces), otherwise, it's split by whitespace, which can be quoted
+/// with `'` or `"`, or escaped with `\` (except on Windows, where `\` is
+/// the path separator).
+fn split_command(command: &str) -> Vec<String> {
+    let command = command.trim();
+    if Path::new(command).is_file() {
+        return vec![command.to_string()];
+    }
+
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote: Option<char> = None;
+    let mut chars = command.chars();
+
+    while let Some(chr) = chars.next() {
+        match (quote, chr) {
+            (Some(q), chr) if chr == q => quote = None,
+            (None, '\'' | '"') => {
+                quote = Some(chr);
+                word.get_or_insert_with(String::new);
+            }
+            (None, chr) if chr.is_whitespace() => words.extend(word.take()),
+            (Some('"') | None, '\\') if !cfg!(windows) => {
+                let escaped = chars.next().unwrap_or('\\');
+                word.get_or_insert_with(String::new).push(escaped);
+            }
+            (_, chr) => word.get_or_insert_with(String::new).push(chr),
+        }
+    }
+
+    words.extend(word);
+    words
+}
+
+/// Joins the lines of the text with spaces for a single-line input.
+pub(crate) fn join_lines(text: &str) -> String {
+    text.lines().collect::<Vec<_>>().join(" ")
+}
+
+/// Creates a new temporary file readable only by the current user.
+fn create_temp_file() -> io::Result<(PathBuf, File)> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    let path = std::env::temp_dir().join(format!(
+        "cliclack-{pid}-{nanos}.txt",
+        pid = std::process::id()
+    ));
+
+    let mut options = OpenOptions::new();
+    options.read(true).write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let file = options.open(&path)?;
+    Ok((path, file))
+}
+
+/// Overwrites the file contents with zeros before removing it.
+fn remove_securely(path: &Path) -> io::Result<()> {
+    if let Ok(mut file) = OpenOptions::new().write(true).open(path) {
+        let len = file.metadata()?.len();
+        file.seek(SeekFrom::Start(0))?;
+        io::copy(&mut io::repeat(0).take(len), &mut file)?;
+        file.sync_all()?;
+    }
+    fs::remove_file(path)
+}
+
+/// Opens the external editor on a temporary file pre-filled with the text,
+/// and returns the edited text.
+///
+/// The trailing newline added by most editors is removed.
+pub(crate) fn edit(text: &str) -> io::Result<String> {
+    edit_with(&editor_command(), text)
+}
+
+/// Opens the text in the given editor command, see [`edit`].
+fn edit_with(command: &str, text: &str) -> io::Result<String> {
+    let (path, mut file) = create_temp_file()?;
+
+    let result = (|| {
+        file.write_all(text.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+
+        let mut args = split_command(command).into_iter();
+        let program = args.next().unwrap_or_default();
+
+        let status = Command::new(&program).args(args).arg(&path).status()?;
+        if !status.success() {
+            return Err(io::Error::other(format!("{program}: {status}")));
+        }
+
+        let mut edited = String::new();
+        File::open(&path)?.read_to_string(&mut edited)?;
+
+        let text = edited
+            .strip_suffix('\n')
+            .map(|s| s.strip_suffix('\r').unwrap_or(s))
+            .unwrap_or(&edited)
+            .to_string();
+        edited.zeroize();
+
+        Ok(text)
+    })();
+
+    let removed = remove_securely(&path);
+    let text = result?;
+    removed?;
+    Ok(text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn command() {
+        assert_eq!(split_command("vi"), ["vi"]);
+        assert_eq!(split_command(" code  --wait "), ["code", "--wait"]);
+        assert_eq!(
+            split_command(r#""/opt/my editor/bin" -n 'a b'"#),
+            ["/opt/my editor/bin", "-n", "a b"]
+        );
+        assert_eq!(
+            split_command(r#"emacs -nw "" x"#),
+            ["emacs", "-nw", "", "x"]
+        );
+        assert!(split_command("").is_empty());
+
+        #[cfg(unix)]
+        assert_eq!(split_command(r"/opt/my\ editor"), ["/opt/my editor"]);
+    }
+
+    #[test]
+    fn single_line() {
+        assert_eq!(join_lines("a\nb\r\nc"), "a b c");
+        assert_eq!(join_lines("a"), "a");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn editing() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // An editor script in a directory with a space in the name.
+        let dir = tempfile::Builder::new()
+            .prefix("cliclack editor ")
+            .tempdir()
+            .unwrap();
+        let script = dir.path().join("edit.sh");
+        // Appends the number of arguments to the file, which is the last one.
+        let body = r#"#!/bin/sh
+eval "file=\${$#}"
+printf '%s %s\n' "$(cat "$file")" $# > "$file"
+"#;
+        fs::write(&script, body).unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o700)).unwrap();
+
+        let quoted = format!("'{}' edited", script.display());
+        assert_eq!(edit_with(&quoted, "text").unwrap(), "text 2");
+
+        let unquoted = script.display().to_string();
+        assert_eq!(edit_with(&unquoted, "text").unwrap(), "text 1");
+
+        assert!(edit_with("false", "text").is_err());
+    }
+}
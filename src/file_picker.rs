@@ -0,0 +1,470 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use console::Key;
+
+use crate::{
+    filter::FilteredView,
+    messages::MESSAGES,
+    prompt::{
+        cursor::StringCursor,
+        interaction::{Event, PromptInteraction, State},
+    },
+    theme::THEME,
+    view::ListView,
+};
+
+/// A directory entry of the file picker.
+struct Entry {
+    path: PathBuf,
+    label: String,
+    is_dir: bool,
+}
+
+impl AsRef<str> for Entry {
+    fn as_ref(&self) -> &str {
+        &self.label
+    }
+}
+
+/// A prompt that browses the filesystem and picks files or directories.
+///
+/// * `Up`/`Down` to move the cursor.
+/// * `Enter` (or `Right`) to descend into a directory.
+/// * `Backspace` (or `Left`) to go to the parent directory.
+/// * Type to filter the entries of the current directory.
+/// * `Tab` to show or hide hidden files.
+/// * `Space` to select multiple entries (see [`FilePicker::interact_multiple`]).
+/// * `Enter` on a file to submit.
+///
+/// # Example
+///
+/// ```
+/// use std::path::PathBuf;
+/// use cliclack::FilePicker;
+///
+/// # fn test() -> std::io::Result<()> {
+/// let manifest: PathBuf = FilePicker::new("Pick a manifest")
+///     .extensions(["toml"])
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// # test().ok(); // Ignoring I/O runtime errors.
+/// ```
+pub struct FilePicker {
+    prompt: String,
+    dir: PathBuf,
+    root: Option<PathBuf>,
+    items: Vec<Rc<RefCell<Entry>>>,
+    cursor: usize,
+    filter: FilteredView<Entry>,
+    filter_empty: bool,
+    view: ListView,
+    extensions: Vec<String>,
+    directories_only: bool,
+    show_hidden: bool,
+    multiple: bool,
+    selected: Vec<PathBuf>,
+    submitted: StringCursor,
+}
+
+impl FilePicker {
+    /// Creates a new file picker prompt starting in the current directory.
+    pub fn new(prompt: impl Display) -> Self {
+        let mut filter = FilteredView::default();
+        filter.enable();
+
+        Self {
+            prompt: prompt.to_string(),
+            dir: PathBuf::from("."),
+            root: None,
+            items: Vec::new(),
+            cursor: 0,
+            filter,
+            filter_empty: true,
+            view: ListView {
+                height: 10,
+                start: 0,
+            },
+            extensions: Vec::new(),
+            directories_only: false,
+            show_hidden: false,
+            multiple: false,
+            selected: Vec::new(),
+            submitted: StringCursor::default(),
+        }
+    }
+
+    /// Sets the root directory: browsing starts there and can't go above it
+    /// (symbolic links and `..` are resolved).
+    pub fn root(mut self, root: impl AsRef<Path>) -> Self {
+        self.root = Some(root.as_ref().to_path_buf());
+        self
+    }
+
+    /// Shows only files with the given extensions (and directories).
+    pub fn extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extensions = extensions
+            .into_iter()
+            .map(|ext| ext.as_ref().trim_start_matches('.').to_string())
+            .collect();
+        self
+    }
+
+    /// Picks directories instead of files. The current directory is picked
+    /// with the `./` entry.
+    pub fn directories_only(mut self) -> Self {
+        self.directories_only = true;
+        self
+    }
+
+    /// Shows hidden files initially. `Tab` toggles them interactively.
+    pub fn show_hidden(mut self, show: bool) -> Self {
+        self.show_hidden = show;
+        self
+    }
+
+    /// The maximum visible number of entries. Default: 10.
+    pub fn max_rows(mut self, height: usize) -> Self {
+        self.view.height = height;
+        self
+    }
+
+    /// Starts the prompt interaction picking a single entry.
+    pub fn interact(&mut self) -> io::Result<PathBuf> {
+        self.multiple = false;
+        let paths = self.start()?;
+        Ok(paths.into_iter().next().unwrap_or_default())
+    }
+
+    /// Starts the prompt interaction picking multiple entries with `Space`.
+    pub fn interact_multiple(&mut self) -> io::Result<Vec<PathBuf>> {
+        self.multiple = true;
+        self.start()
+    }
+
+    fn start(&mut self) -> io::Result<Vec<PathBuf>> {
+        let dir = self.root.clone().unwrap_or_else(|| self.dir.clone());
+        self.load(dir)?;
+        <Self as PromptInteraction<Vec<PathBuf>>>::interact(self)
+    }
+
+    /// Reads the directory and makes it current. Unreadable entries are skipped.
+    fn load(&mut self, dir: PathBuf) -> io::Result<()> {
+        let mut entries: Vec<Entry> = fs::read_dir(&dir)?
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let path = entry.path();
+                let is_dir = path.is_dir();
+
+                if name.starts_with('.') && !self.show_hidden {
+                    return None;
+                }
+                if !is_dir && (self.directories_only || !self.has_extension(&path)) {
+                    return None;
+                }
+
+                Some(Entry {
+                    label: if is_dir { format!("{name}/") } else { name },
+                    path,
+                    is_dir,
+                })
+            })
+            .collect();
+
+        entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then(a.label.cmp(&b.label)));
+
+        if self.directories_only {
+            entries.insert(
+                0,
+                Entry {
+                    path: dir.clone(),
+                    label: "./".into(),
+                    is_dir: false,
+                },
+            );
+        }
+
+        self.dir = dir;
+        self.items = entries
+            .into_iter()
+            .map(|entry| Rc::new(RefCell::new(entry)))
+            .collect();
+        self.filter.set(self.items.to_vec());
+        if let Some(input) = self.filter.input() {
+            input.clear();
+        }
+        self.cursor = 0;
+        self.view.start = 0;
+        Ok(())
+    }
+
+    fn has_extension(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .is_some_and(|ext| self.extensions.iter().any(|e| *ext == **e))
+    }
+
+    fn current(&self) -> Option<Rc<RefCell<Entry>>> {
+        self.filter.items().get(self.cursor).cloned()
+    }
+
+    /// Goes to the directory, staying in the current one if it can't be read.
+    fn enter(&mut self, dir: PathBuf) -> State<Vec<PathBuf>> {
+        match self.load(dir) {
+            Ok(()) => State::Active,
+            Err(err) => State::Error(
+                MESSAGES
+                    .read()
+                    .unwrap()
+                    .directory_not_readable(&err.to_string()),
+            ),
+        }
+    }
+
+    /// Goes to the parent directory unless the current one is the root.
+    fn leave(&mut self) -> State<Vec<PathBuf>> {
+        let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let dir = canonical(&self.dir);
+
+        if let Some(root) = &self.root {
+            let root = canonical(root);
+            if dir == root || !dir.starts_with(&root) {
+                return State::Active;
+            }
+        }
+
+        match dir.parent() {
+            Some(parent) => self.enter(parent.to_path_buf()),
+            None => State::Active,
+        }
+    }
+
+    fn toggle(&mut self, path: PathBuf) {
+        match self.selected.iter().position(|p| *p == path) {
+            Some(i) => {
+                self.selected.remove(i);
+            }
+            None => self.selected.push(path),
+        }
+    }
+
+    fn submit(&mut self, paths: Vec<PathBuf>) -> State<Vec<PathBuf>> {
+        let text = paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.submitted.extend(&text);
+        State::Submit(paths)
+    }
+
+    fn handle(&mut self, key: &Key) -> State<Vec<PathBuf>> {
+        let current = self.current();
+        let (is_dir, path) = match &current {
+            Some(entry) => (entry.borrow().is_dir, Some(entry.borrow().path.clone())),
+            None => (false, None),
+        };
+        // A file, or a directory in the directory mode, can be picked.
+        let selectable = path.is_some() && (!is_dir || self.directories_only);
+
+        match key {
+            Key::Backspace | Key::ArrowLeft if self.filter_empty => return self.leave(),
+            Key::Tab => {
+                self.show_hidden = !self.show_hidden;
+                return self.enter(self.dir.clone());
+            }
+            Key::Char(' ') if self.multiple && selectable => {
+                self.toggle(path.clone().unwrap_or_default())
+            }
+            _ => {}
+        }
+
+        if let Some(state) = self.filter.on(key, &self.items, &self.view) {
+            if self.filter.items().is_empty() || self.cursor > self.filter.items().len() - 1 {
+                self.cursor = 0;
+                self.view.start = 0;
+            }
+            return state;
+        }
+
+        match key {
+            Key::ArrowUp => {
+                self.cursor = self.cursor.saturating_sub(1);
+                if self.cursor < self.view.start {
+                    self.view.start = self.cursor;
+                }
+            }
+            Key::ArrowDown => {
+                if self.cursor + 1 < self.filter.items().len() {
+                    self.cursor += 1;
+                }
+                if self.cursor >= self.view.start + self.view.height {
+                    self.view.start = self.cursor + 1 - self.view.height;
+                }
+            }
+            Key::ArrowRight | Key::Enter if is_dir => {
+                if let Some(path) = path {
+                    return self.enter(path);
+                }
+            }
+            Key::Enter if self.multiple && !self.selected.is_empty() => {
+                return self.submit(self.selected.clone());
+            }
+            Key::Enter if selectable => {
+                return self.submit(path.into_iter().collect());
+            }
+            _ => {}
+        }
+
+        State::Active
+    }
+}
+
+impl PromptInteraction<Vec<PathBuf>> for FilePicker {
+    fn on(&mut self, event: &Event) -> State<Vec<PathBuf>> {
+        let Event::Key(key) = event;
+        let state = self.handle(key);
+        self.filter_empty = self.filter.input().is_none_or(|input| input.is_empty());
+        state
+    }
+
+    fn render(&mut self, state: &State<Vec<PathBuf>>) -> String {
+        let theme = THEME.read().unwrap();
+
+        let header = theme.format_header(&state.into(), &self.prompt);
+        let footer = theme.format_footer(&state.into());
+
+        if let State::Submit(_) | State::Cancel = state {
+            return header + &theme.format_input(&state.into(), &self.submitted) + &footer;
+        }
+
+        let filter = match self.filter.input() {
+            Some(input) if !input.is_empty() => theme.format_input(&state.into(), input),
+            _ => {
+                let mut dir = StringCursor::default();
+                dir.extend(&format!("{}/", self.dir.display()));
+                theme.format_placeholder(&state.into(), &dir)
+            }
+        };
+
+        let items: String = self
+            .filter
+            .items()
+            .iter()
+            .enumerate()
+            .skip(self.view.start)
+            .take(self.view.height)
+            .map(|(i, entry)| {
+                let entry = entry.borrow();
+                let matches = self.filter.positions(&entry.label);
+                match self.multiple {
+                    true => theme.format_multiselect_item_matched(
+                        &state.into(),
+                        self.selected.contains(&entry.path),
+                        self.cursor == i,
+                        &entry.label,
+                        "",
+                        &matches,
+                    ),
+                    false => theme.format_select_item_matched(
+                        &state.into(),
+                        self.cursor == i,
+                        &entry.label,
+                        "",
+                        &matches,
+                    ),
+                }
+            })
+            .collect();
+
+        header + &filter + &items + &footer
+    }
+
+    fn input(&mut self) -> Option<&mut StringCursor> {
+        self.filter.input()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn picker(root: &Path) -> FilePicker {
+        let mut picker = FilePicker::new("Pick").root(root);
+        picker.load(root.to_path_buf()).unwrap();
+        picker
+    }
+
+    fn press(picker: &mut FilePicker, key: Key) -> State<Vec<PathBuf>> {
+        picker.on(&Event::Key(key))
+    }
+
+    fn labels(picker: &FilePicker) -> Vec<String> {
+        let items = picker.filter.items();
+        items
+            .iter()
+            .map(|entry| entry.borrow().label.clone())
+            .collect()
+    }
+
+    #[test]
+    fn browsing() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("root/sub")).unwrap();
+        fs::write(dir.path().join("root/sub/a.toml"), "").unwrap();
+        fs::write(dir.path().join("root/sub/b.rs"), "").unwrap();
+        fs::write(dir.path().join("root/.hidden.toml"), "").unwrap();
+
+        // The root is given in a non-canonical form.
+        let mut picker = picker(&dir.path().join("root/sub/..")).extensions(["toml"]);
+        assert_eq!(labels(&picker), vec!["sub/"]);
+
+        // Can't go above the root.
+        press(&mut picker, Key::ArrowLeft);
+        assert_eq!(labels(&picker), vec!["sub/"]);
+
+        press(&mut picker, Key::Tab);
+        assert_eq!(labels(&picker), vec!["sub/", ".hidden.toml"]);
+        press(&mut picker, Key::Tab);
+
+        press(&mut picker, Key::Enter);
+        assert_eq!(labels(&picker), vec!["a.toml"]);
+        let state = press(&mut picker, Key::Enter);
+        assert!(matches!(state, State::Submit(paths) if paths == [picker.dir.join("a.toml")]));
+
+        press(&mut picker, Key::Backspace);
+        assert_eq!(labels(&picker), vec!["sub/"]);
+        press(&mut picker, Key::Backspace);
+        assert_eq!(labels(&picker), vec!["sub/"]);
+    }
+
+    #[test]
+    fn unreadable_directory() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("gone")).unwrap();
+
+        let mut picker = picker(dir.path());
+        fs::remove_dir(dir.path().join("gone")).unwrap();
+
+        // The error is shown, and the current directory is kept.
+        assert!(matches!(press(&mut picker, Key::Enter), State::Error(_)));
+        assert_eq!(picker.dir, dir.path());
+        assert_eq!(labels(&picker), vec!["gone/"]);
+
+        assert!(FilePicker::new("Pick")
+            .load(dir.path().join("gone"))
+            .is_err());
+    }
+}
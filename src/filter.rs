@@ -2,8 +2,14 @@ use std::{cell::RefCell, rc::Rc};
 
 use console::Key;
 
+use crate::matcher::{Index, JaroWinkler, Matcher};
+use crate::messages::MESSAGES;
 use crate::prompt::{cursor::StringCursor, interaction::State};
-use crate::suggest::Suggest;
+use crate::view::ListView;
+
+/// The number of items ranked beyond the visible ones, so scrolling down
+/// ranks the items again only once in a while.
+const HEADROOM: usize = 64;
 
 /// The list of items gathered (filtered) by interactive input using
 /// `FilteredView::on` event in a selection prompt.
@@ -17,6 +23,12 @@ pub(crate) struct FilteredView<I: AsRef<str>> {
     input: StringCursor,
     /// Represents a view of the filtered items.
     items: Vec<Rc<RefCell<I>>>,
+    /// Prepared labels of all items.
+    index: Index,
+    /// Ranks the items matching the input.
+    matcher: Box<dyn Matcher>,
+    /// The number of the best matching items to rank.
+    limit: usize,
 }
 
 impl<I> Default for FilteredView<I>
@@ -28,6 +40,9 @@ where
             enabled: false,
             input: StringCursor::default(),
             items: vec![],
+            index: Index::new(vec![]),
+            matcher: Box::new(JaroWinkler::default()),
+            limit: usize::MAX,
         }
     }
 }
@@ -42,7 +57,9 @@ where
     /// a builder pattern of the selection prompt, where the items are added one by one,
     /// and the filter can be enabled and initialized at different moment of time.
     pub fn set(&mut self, items: Vec<Rc<RefCell<I>>>) {
+        self.index = Index::new(items.iter().map(|i| i.borrow().as_ref().into()).collect());
         self.items = items;
+        self.limit = usize::MAX;
     }
 
     /// Sets a predefined set of items for the view.
@@ -50,30 +67,51 @@ where
         self.enabled = true;
     }
 
+    /// Sets the matcher ranking the items.
+    pub fn set_matcher(&mut self, matcher: impl Matcher + 'static) {
+        self.matcher = Box::new(matcher);
+    }
+
     /// Returns the items in the view.
     pub fn items(&self) -> &[Rc<RefCell<I>>] {
         &self.items
     }
 
     /// Collects the input and filters the items from the list of suggestions.
-    pub fn on<T, S>(&mut self, key: &Key, all_items: &S) -> Option<State<T>>
-    where
-        S: Suggest<Result = Rc<RefCell<I>>>,
-    {
+    ///
+    /// Only the best matching items visible in the `view` (with some headroom
+    /// for scrolling) are ranked, and more of them as the view scrolls down.
+    pub fn on<T>(
+        &mut self,
+        key: &Key,
+        all_items: &[Rc<RefCell<I>>],
+        view: &ListView,
+    ) -> Option<State<T>> {
         if !self.enabled {
             // Pass over the control.
             return None;
         }
 
+        // The items which can be visible after moving the cursor one item down.
+        let visible = view.start.saturating_add(view.height);
+
         match key {
             // Need further processing of simple "up" and "down" actions.
-            Key::ArrowDown | Key::ArrowUp => None,
+            Key::ArrowUp => None,
+            Key::ArrowDown => {
+                self.extend(visible.saturating_add(1), all_items);
+                None
+            }
             // Need moving up and down if no input provided.
-            Key::ArrowLeft | Key::ArrowRight if self.input.is_empty() => None,
+            Key::ArrowLeft if self.input.is_empty() => None,
+            Key::ArrowRight if self.input.is_empty() => {
+                self.extend(visible.saturating_add(1), all_items);
+                None
+            }
             // Need to submit the selected item.
             Key::Enter if !self.items.is_empty() => None,
             // Otherwise, no items found.
-            Key::Enter => Some(State::Error("No items".into())),
+            Key::Enter => Some(State::Error(MESSAGES.read().unwrap().no_items())),
             // Ignore spaces passing through.
             Key::Char(' ') => {
                 self.input.delete_left();
@@ -81,12 +119,41 @@ where
             }
             // Refresh the filtered items for the rest of the keys.
             _ => {
-                self.items = all_items.suggest(&self.input.to_string());
+                self.limit = visible.saturating_add(HEADROOM);
+                self.rank(all_items);
                 Some(State::Active)
             }
         }
     }
 
+    /// Ranks at least `len` items, if more than ranked before can match.
+    fn extend(&mut self, len: usize, all_items: &[Rc<RefCell<I>>]) {
+        if self.items.len() == self.limit && len > self.limit {
+            self.limit = len.saturating_add(HEADROOM);
+            self.rank(all_items);
+        }
+    }
+
+    /// Filters the best matching items up to the limit.
+    fn rank(&mut self, all_items: &[Rc<RefCell<I>>]) {
+        let query = self.input.to_string();
+        self.items = self
+            .index
+            .search(&query, &*self.matcher, self.limit)
+            .into_iter()
+            .map(|i| Rc::clone(&all_items[i]))
+            .collect();
+    }
+
+    /// Returns the character indices of the label matching the filter text
+    /// (none if the filter is disabled).
+    pub fn positions(&self, label: &str) -> Vec<usize> {
+        match self.enabled && !self.input.is_empty() {
+            true => self.matcher.positions(label, &self.input.to_string()),
+            false => vec![],
+        }
+    }
+
     /// Returns the input cursor if the filter is enabled.
     /// It makes the outer code to handle the input.
     pub fn input(&mut self) -> Option<&mut StringCursor> {
@@ -97,3 +164,49 @@ where
         Some(&mut self.input)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matcher::Subsequence;
+
+    fn numbers(len: usize) -> Vec<Rc<RefCell<String>>> {
+        (0..len)
+            .map(|i| Rc::new(RefCell::new(format!("item {i}"))))
+            .collect()
+    }
+
+    #[test]
+    fn ranking_limit() {
+        let items = numbers(1000);
+        let mut filter = FilteredView::default();
+        filter.set(items.clone());
+        filter.set_matcher(Subsequence::new());
+        filter.enable();
+
+        // The visible items are ranked with the headroom for scrolling.
+        let mut view = ListView {
+            height: 5,
+            start: 0,
+        };
+        filter.input().unwrap().insert('i');
+        filter.on::<()>(&Key::Char('i'), &items, &view);
+        assert_eq!(filter.items().len(), 5 + HEADROOM);
+
+        // More items are ranked only when scrolling beyond the ranked ones.
+        view.start = HEADROOM - 1;
+        filter.on::<()>(&Key::ArrowDown, &items, &view);
+        assert_eq!(filter.items().len(), 5 + HEADROOM);
+        view.start = HEADROOM;
+        filter.on::<()>(&Key::ArrowDown, &items, &view);
+        assert_eq!(filter.items().len(), 6 + 2 * HEADROOM);
+
+        // All matching items, if fewer.
+        let items = numbers(10);
+        filter.set(items.clone());
+        filter.on::<()>(&Key::Char('i'), &items, &view);
+        assert_eq!(filter.items().len(), 10);
+        filter.on::<()>(&Key::ArrowDown, &items, &view);
+        assert_eq!(filter.items().len(), 10);
+    }
+}
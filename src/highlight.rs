@@ -0,0 +1,327 @@
+//! Syntax highlighting of the [`Input`](crate::Input) text.
+//!
+//! A highlighter returns styled spans of the text, see [`Highlight`].
+//! Ready-made [`Json`] and [`Toml`] highlighters are available with the
+//! `highlight` feature.
+
+use std::ops::Range;
+
+use console::Style;
+
+/// A styled span of the text.
+#[derive(Clone, Debug)]
+pub struct Span {
+    /// The range of character (not byte) indices.
+    pub range: Range<usize>,
+    /// The style of the characters.
+    pub style: Style,
+}
+
+impl Span {
+    /// Creates a new span of the given character range.
+    pub fn new(range: Range<usize>, style: Style) -> Self {
+        Self { range, style }
+    }
+}
+
+/// Highlights the input text returning styled spans.
+///
+/// Characters not covered by any span keep the default input style. If spans
+/// overlap, the last one wins.
+///
+/// A generic implementation for `Fn(&str) -> Vec<Span>` is provided.
+///
+/// ```
+/// use cliclack::{highlight::Span, input};
+/// use console::Style;
+///
+/// # fn test() -> std::io::Result<()> {
+/// let command: String = input("Command")
+///     .highlight(|text: &str| {
+///         // Highlight the first word.
+///         let len = text.chars().take_while(|c| !c.is_whitespace()).count();
+///         vec![Span::new(0..len, Style::new().green().bold())]
+///     })
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// # test().ok(); // Ignoring I/O runtime errors.
+/// ```
+pub trait Highlight {
+    /// Returns the styled spans of the text.
+    fn highlight(&self, text: &str) -> Vec<Span>;
+}
+
+impl<F> Highlight for F
+where
+    F: Fn(&str) -> Vec<Span>,
+{
+    fn highlight(&self, text: &str) -> Vec<Span> {
+        self(text)
+    }
+}
+
+#[cfg(feature = "highlight")]
+pub use syntax::{Json, Toml};
+
+#[cfg(feature = "highlight")]
+mod syntax {
+    use super::*;
+
+    fn key() -> Style {
+        Style::new().cyan()
+    }
+
+    fn string() -> Style {
+        Style::new().green()
+    }
+
+    fn number() -> Style {
+        Style::new().yellow()
+    }
+
+    fn keyword() -> Style {
+        Style::new().magenta()
+    }
+
+    fn punctuation() -> Style {
+        Style::new().dim()
+    }
+
+    fn error() -> Style {
+        Style::new().red().underlined()
+    }
+
+    /// Returns the end of a quoted string starting at `start`, and whether
+    /// it's terminated on the same line.
+    fn quoted(chars: &[char], start: usize) -> (usize, bool) {
+        let quote = chars[start];
+        let mut i = start + 1;
+
+        while i < chars.len() && chars[i] != '\n' {
+            match chars[i] {
+                '\\' if quote == '"' => i += 2,
+                c if c == quote => return (i + 1, true),
+                _ => i += 1,
+            }
+        }
+
+        (i.min(chars.len()), false)
+    }
+
+    /// Returns the end of a run of characters matching the predicate.
+    fn run(chars: &[char], start: usize, predicate: impl Fn(char) -> bool) -> usize {
+        start + chars[start..].iter().take_while(|c| predicate(**c)).count()
+    }
+
+    fn is_number(word: &[char]) -> bool {
+        let word: String = word.iter().collect();
+        word.replace('_', "").parse::<f64>().is_ok()
+    }
+
+    /// Highlights JSON: keys, strings, numbers, keywords and punctuation.
+    /// Unknown tokens and unterminated strings are styled as errors.
+    #[derive(Default, Clone, Copy)]
+    pub struct Json;
+
+    impl Highlight for Json {
+        fn highlight(&self, text: &str) -> Vec<Span> {
+            let chars: Vec<char> = text.chars().collect();
+            let mut spans = vec![];
+            let mut i = 0;
+
+            while i < chars.len() {
+                let start = i;
+                let style = match chars[i] {
+                    c if c.is_whitespace() => {
+                        i += 1;
+                        continue;
+                    }
+                    '"' => {
+                        let (end, terminated) = quoted(&chars, i);
+                        i = end;
+                        let is_key = chars[i..]
+                            .iter()
+                            .find(|c| !c.is_whitespace())
+                            .is_some_and(|c| *c == ':');
+                        match (terminated, is_key) {
+                            (false, _) => error(),
+                            (true, true) => key(),
+                            (true, false) => string(),
+                        }
+                    }
+                    '{' | '}' | '[' | ']' | ':' | ',' => {
+                        i += 1;
+                        punctuation()
+                    }
+                    _ => {
+                        i = run(&chars, i, |c| !c.is_whitespace() && !"{}[]:,\"".contains(c));
+                        let word: String = chars[start..i].iter().collect();
+                        match word.as_str() {
+                            "true" | "false" | "null" => keyword(),
+                            _ if is_number(&chars[start..i]) => number(),
+                            _ => error(),
+                        }
+                    }
+                };
+
+                spans.push(Span::new(start..i, style));
+            }
+
+            spans
+        }
+    }
+
+    /// Highlights TOML: tables, keys, strings, numbers, booleans and comments.
+    /// Lines which are not a table, a key-value pair or a comment are styled
+    /// as errors.
+    #[derive(Default, Clone, Copy)]
+    pub struct Toml;
+
+    impl Toml {
+        /// Highlights a value (after `=`) up to the end of the line.
+        fn value(chars: &[char], mut i: usize, spans: &mut Vec<Span>) -> usize {
+            while i < chars.len() && chars[i] != '\n' {
+                let start = i;
+                let style = match chars[i] {
+                    c if c.is_whitespace() => {
+                        i += 1;
+                        continue;
+                    }
+                    '#' => {
+                        i = run(chars, i, |c| c != '\n');
+                        punctuation()
+                    }
+                    '"' | '\'' => {
+                        let (end, terminated) = quoted(chars, i);
+                        i = end;
+                        match terminated {
+                            true => string(),
+                            false => error(),
+                        }
+                    }
+                    '[' | ']' | '{' | '}' | ',' | '=' => {
+                        i += 1;
+                        punctuation()
+                    }
+                    _ => {
+                        i = run(chars, i, |c| {
+                            !c.is_whitespace() && !"[]{},=#\"'".contains(c)
+                        });
+                        let word = &chars[start..i];
+                        match word.iter().collect::<String>().as_str() {
+                            "true" | "false" => keyword(),
+                            _ if is_number(word) => number(),
+                            // Dates and times.
+                            _ if word[0].is_ascii_digit() => number(),
+                            // Keys of inline tables.
+                            _ => key(),
+                        }
+                    }
+                };
+                spans.push(Span::new(start..i, style));
+            }
+            i
+        }
+    }
+
+    impl Highlight for Toml {
+        fn highlight(&self, text: &str) -> Vec<Span> {
+            let chars: Vec<char> = text.chars().collect();
+            let mut spans = vec![];
+            let mut i = 0;
+
+            while i < chars.len() {
+                i = run(&chars, i, |c| c.is_whitespace());
+                if i >= chars.len() {
+                    break;
+                }
+
+                let start = i;
+                let line_end = run(&chars, i, |c| c != '\n');
+
+                match chars[i] {
+                    '#' => {
+                        spans.push(Span::new(start..line_end, punctuation()));
+                        i = line_end;
+                    }
+                    '[' => {
+                        let end = chars[start..line_end]
+                            .iter()
+                            .position(|c| *c == ']')
+                            .map(|p| start + p + 1);
+                        match end {
+                            Some(end) => {
+                                let end = run(&chars, end, |c| c == ']');
+                                spans.push(Span::new(start..end, key().bold()));
+                                i = Self::value(&chars, end, &mut spans);
+                            }
+                            None => {
+                                spans.push(Span::new(start..line_end, error()));
+                                i = line_end;
+                            }
+                        }
+                    }
+                    _ => match chars[start..line_end].iter().position(|c| *c == '=') {
+                        Some(eq) => {
+                            spans.push(Span::new(start..start + eq, key()));
+                            spans.push(Span::new(start + eq..start + eq + 1, punctuation()));
+                            i = Self::value(&chars, start + eq + 1, &mut spans);
+                        }
+                        None => {
+                            spans.push(Span::new(start..line_end, error()));
+                            i = line_end;
+                        }
+                    },
+                }
+            }
+
+            spans
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        fn styled(spans: &[Span], text: &str) -> Vec<(String, String)> {
+            let chars: Vec<char> = text.chars().collect();
+            spans
+                .iter()
+                .map(|span| {
+                    (
+                        chars[span.range.clone()].iter().collect(),
+                        format!("{:?}", span.style),
+                    )
+                })
+                .collect()
+        }
+
+        #[test]
+        fn json() {
+            let text = r#"{"a": [1, true, "x", oops]}"#;
+            let spans = styled(&Json.highlight(text), text);
+            let style = |token: &str| &spans.iter().find(|(t, _)| t == token).unwrap().1;
+
+            assert_eq!(*style("\"a\""), format!("{:?}", key()));
+            assert_eq!(*style("1"), format!("{:?}", number()));
+            assert_eq!(*style("true"), format!("{:?}", keyword()));
+            assert_eq!(*style("\"x\""), format!("{:?}", string()));
+            assert_eq!(*style("oops"), format!("{:?}", error()));
+        }
+
+        #[test]
+        fn toml() {
+            let text = "[package]\nname = \"app\" # comment\nversion = 1\noops";
+            let spans = styled(&Toml.highlight(text), text);
+            let style = |token: &str| &spans.iter().find(|(t, _)| t == token).unwrap().1;
+
+            assert_eq!(*style("[package]"), format!("{:?}", key().bold()));
+            assert_eq!(*style("name "), format!("{:?}", key()));
+            assert_eq!(*style("\"app\""), format!("{:?}", string()));
+            assert_eq!(*style("# comment"), format!("{:?}", punctuation()));
+            assert_eq!(*style("1"), format!("{:?}", number()));
+            assert_eq!(*style("oops"), format!("{:?}", error()));
+        }
+    }
+}
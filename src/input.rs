@@ -1,20 +1,26 @@
 use std::io;
+use std::time::Duration;
 use std::{fmt::Display, str::FromStr};
 
 use console::Key;
 
 use crate::autocomplete::Autocomplete;
-use crate::suggest::Suggest;
+use crate::highlight::Highlight;
+use crate::suggest::{Suggest, Suggestion};
 use crate::{
+    messages::MESSAGES,
     prompt::{
         cursor::StringCursor,
         interaction::{Event, PromptInteraction, State},
+        mask::Mask,
     },
     theme::THEME,
     validate::Validate,
+    view::ListView,
 };
 
 type ValidationCallback = Box<dyn Fn(&String) -> Result<(), String>>;
+type ParseErrorCallback = Box<dyn Fn(&str, &str) -> String>;
 
 #[derive(Default, PartialEq)]
 enum Multiline {
@@ -24,6 +30,15 @@ enum Multiline {
     Editing,
 }
 
+/// A value of the input with a mask, see [`Input::mask_pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Masked {
+    /// The entered characters without literals of the mask, e.g. `5551234567`.
+    pub raw: String,
+    /// The value formatted by the mask, e.g. `(555) 123-4567`.
+    pub formatted: String,
+}
+
 /// A prompt that accepts a text input: either single-line or multiline.
 ///
 /// # Example
@@ -55,17 +70,62 @@ enum Multiline {
 /// # }
 /// # test().ok(); // Ignoring I/O runtime errors.
 /// ```
-#[derive(Default)]
-pub struct Input {
+pub struct Input<T = Untyped> {
     prompt: String,
     input: StringCursor,
     input_required: bool,
     default: Option<String>,
+    default_value: Option<T>,
     placeholder: StringCursor,
     multiline: Multiline,
+    editor: bool,
+    ctrl_x: bool,
+    view: ListView,
+    line_numbers: bool,
+    highlight: Option<Box<dyn Highlight>>,
     validate_on_enter: Option<ValidationCallback>,
     validate_interactively: Option<ValidationCallback>,
-    autocomplete: Option<Autocomplete>,
+    parse_error: Option<ParseErrorCallback>,
+    autocomplete: Option<Autocomplete<T>>,
+    autocomplete_rows: Option<usize>,
+    autocomplete_strict: bool,
+    autocomplete_shell_style: bool,
+    ghost_text: bool,
+    hide_popup: bool,
+}
+
+/// The value type of an [`Input`] which is not fixed at build time:
+/// [`Input::interact`] parses the input into any [`FromStr`] type.
+///
+/// See [`Input::typed`] for fixing the value type.
+pub enum Untyped {}
+
+impl<T> Default for Input<T> {
+    fn default() -> Self {
+        Self {
+            prompt: String::new(),
+            input: StringCursor::default(),
+            input_required: false,
+            default: None,
+            default_value: None,
+            placeholder: StringCursor::default(),
+            multiline: Multiline::default(),
+            editor: false,
+            ctrl_x: false,
+            view: ListView::default(),
+            line_numbers: false,
+            highlight: None,
+            validate_on_enter: None,
+            validate_interactively: None,
+            parse_error: None,
+            autocomplete: None,
+            autocomplete_rows: None,
+            autocomplete_strict: false,
+            autocomplete_shell_style: false,
+            ghost_text: false,
+            hide_popup: false,
+        }
+    }
 }
 
 impl Input {
@@ -78,6 +138,180 @@ impl Input {
         }
     }
 
+    /// Fixes the type of the value returned by [`Input::interact`] at build
+    /// time, which enables typed defaults (see [`Input::default_value`]).
+    ///
+    /// ```
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let port = Input::new("Port").typed::<u16>().interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn typed<T: FromStr + 'static>(self) -> Input<T> {
+        Input {
+            prompt: self.prompt,
+            input: self.input,
+            input_required: self.input_required,
+            default: self.default,
+            default_value: None,
+            placeholder: self.placeholder,
+            multiline: self.multiline,
+            editor: self.editor,
+            ctrl_x: self.ctrl_x,
+            view: self.view,
+            line_numbers: self.line_numbers,
+            highlight: self.highlight,
+            validate_on_enter: self.validate_on_enter,
+            validate_interactively: self.validate_interactively,
+            parse_error: self.parse_error,
+            autocomplete: self.autocomplete.map(Autocomplete::typed),
+            autocomplete_rows: self.autocomplete_rows,
+            autocomplete_strict: self.autocomplete_strict,
+            autocomplete_shell_style: self.autocomplete_shell_style,
+            ghost_text: self.ghost_text,
+            hide_popup: self.hide_popup,
+        }
+    }
+
+    /// Sets a typed default value for the input, which is displayed in the
+    /// hint (placeholder) the same way as [`Input::default_input`], and fixes
+    /// the type of the value returned by [`Input::interact`].
+    ///
+    /// If no value has been supplied, the default value is returned as-is
+    /// without parsing it back from the text.
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv4Addr};
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let host: IpAddr = Input::new("Host")
+    ///     .default_value(IpAddr::V4(Ipv4Addr::LOCALHOST))
+    ///     .interact()?;
+    /// let port = Input::new("Port").default_value(8080u16).interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn default_value<T>(self, value: T) -> Input<T>
+    where
+        T: FromStr + Display + 'static,
+    {
+        self.typed::<T>().default_value(value)
+    }
+
+    /// Enables autocomplete suggestions with hints, inserted texts
+    /// and typed values, see [`Suggestion`], which fixes the type of the value
+    /// returned by [`Input::interact`].
+    ///
+    /// If an accepted suggestion has a value, and the inserted text is not
+    /// edited afterwards, [`Input::interact`] returns the value as-is
+    /// without parsing the input text.
+    ///
+    /// ```
+    /// use cliclack::{Input, Suggestion};
+    ///
+    /// # #[derive(Clone, Debug, PartialEq)]
+    /// # struct Version(u32, u32);
+    /// # impl std::str::FromStr for Version {
+    /// #     type Err = String;
+    /// #     fn from_str(s: &str) -> Result<Self, String> { Err(s.into()) }
+    /// # }
+    /// # fn test() -> std::io::Result<()> {
+    /// let version: Version = Input::new("Version")
+    ///     .autocomplete_items(vec![
+    ///         Suggestion::new("stable").hint("1.95").value(Version(1, 95)),
+    ///         Suggestion::new("beta").hint("1.96").value(Version(1, 96)),
+    ///     ])
+    ///     .interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn autocomplete_items<S, T>(self, source: S) -> Input<T>
+    where
+        S: Suggest<Result = Suggestion<T>> + 'static,
+        T: FromStr + 'static,
+    {
+        self.typed::<T>().autocomplete_items(source)
+    }
+
+    /// Starts the prompt interaction.
+    ///
+    /// The input is parsed into `T`, and the parse error is shown to the user
+    /// (see [`Input::parse_error`]).
+    pub fn interact<T>(&mut self) -> io::Result<T>
+    where
+        T: FromStr + 'static,
+        T::Err: Display,
+    {
+        self.prepare();
+        <Self as PromptInteraction<T>>::interact(self)
+    }
+
+    /// Starts the prompt interaction returning both the raw and the formatted
+    /// values of the input with a mask (see [`Input::mask_pattern`]).
+    ///
+    /// ```
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let phone = Input::new("Phone")
+    ///     .mask_pattern("(###) ###-####")
+    ///     .interact_masked()?;
+    /// println!("{} ({})", phone.formatted, phone.raw);
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn interact_masked(&mut self) -> io::Result<Masked> {
+        let formatted: String = self.interact()?;
+        Ok(Masked {
+            raw: self.input.raw_value(),
+            formatted,
+        })
+    }
+}
+
+impl<T: FromStr> Input<T> {
+    /// Sets the typed default value of the input, see
+    /// [`Input::default_value`](Input<Untyped>::default_value).
+    pub fn default_value(mut self, value: T) -> Self
+    where
+        T: Display,
+    {
+        self.default = Some(value.to_string());
+        self.default_value = Some(value);
+        self
+    }
+
+    /// Enables autocomplete suggestions with typed values, see
+    /// [`Input::autocomplete_items`](Input<Untyped>::autocomplete_items).
+    pub fn autocomplete_items<S>(mut self, source: S) -> Self
+    where
+        S: Suggest<Result = Suggestion<T>> + 'static,
+        T: 'static,
+    {
+        self.set_autocomplete(Autocomplete::new(source));
+        self
+    }
+
+    /// Starts the prompt interaction returning the value of the type fixed
+    /// at build time.
+    pub fn interact(&mut self) -> io::Result<T>
+    where
+        T: 'static,
+        T::Err: Display,
+    {
+        self.prepare();
+        <Self as PromptInteraction<T>>::interact(self)
+    }
+}
+
+impl<T> Input<T> {
     /// Sets the placeholder (hint) text for the input.
     pub fn placeholder(mut self, placeholder: &str) -> Self {
         self.placeholder.extend(placeholder);
@@ -90,6 +324,7 @@ impl Input {
     /// is used is no value has been supplied.
     pub fn default_input(mut self, value: &str) -> Self {
         self.default = Some(value.into());
+        self.default_value = None;
         self
     }
 
@@ -101,6 +336,44 @@ impl Input {
         self
     }
 
+    /// Sets a mask pattern, so only matching characters are accepted, literals
+    /// are inserted automatically, and the unfilled part is shown as a hint.
+    ///
+    /// * `#`: a digit.
+    /// * `H`: a hexadecimal digit.
+    /// * `A`: a letter.
+    /// * `*`: a letter or a digit.
+    /// * `\`: escapes the next character, making it a literal.
+    /// * Any other character is a literal.
+    ///
+    /// A non-empty input must fill the whole mask. [`Input::interact`] returns
+    /// the formatted value, and [`Input::interact_masked`] returns both the raw
+    /// and the formatted values.
+    ///
+    /// ```
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let mac: String = Input::new("MAC address")
+    ///     .mask_pattern("HH:HH:HH:HH:HH:HH")
+    ///     .interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn mask_pattern(mut self, pattern: &str) -> Self {
+        self.input.set_mask(Mask::new(pattern));
+        self
+    }
+
+    /// Limits the input length in characters, and shows a live counter
+    /// (like `42/63`) in the footer. Characters beyond the limit are ignored,
+    /// including pasted text, but a longer default value is submitted as is.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.input.set_max_len(max_len);
+        self
+    }
+
     /// Enables multiline input.
     ///
     /// 1. Press `Esc` to review and submit.
@@ -110,6 +383,52 @@ impl Input {
         self
     }
 
+    /// The maximum visible number of lines of the multiline input.
+    ///
+    /// If the input has more lines, the visible lines follow the cursor,
+    /// and the cursor position (`line X/Y`) is shown in the footer.
+    /// By default, all lines are displayed.
+    pub fn max_lines(mut self, height: usize) -> Self {
+        self.view.height = height.max(1);
+        self
+    }
+
+    /// Shows line numbers in the gutter of the input.
+    pub fn line_numbers(mut self) -> Self {
+        self.line_numbers = true;
+        self
+    }
+
+    /// Enables editing in the external editor (`$VISUAL` or `$EDITOR`) by
+    /// `Ctrl-X Ctrl-E`, which is useful for long multiline input.
+    ///
+    /// The editor opens a temporary file pre-filled with the current input.
+    /// When the editor exits, the input is replaced with the file contents,
+    /// and the multiline input switches to the preview mode. The lines are
+    /// joined with spaces for a single-line input. The temporary file is
+    /// overwritten with zeros and removed.
+    ///
+    /// The editor command may contain arguments, e.g. `code --wait`; quote
+    /// the program path if it contains spaces.
+    pub fn editor(mut self) -> Self {
+        self.editor = true;
+        self
+    }
+
+    /// Highlights the input text while editing, e.g. by syntax.
+    ///
+    /// The highlighter returns styled spans of the text, see [`Highlight`].
+    /// Ready-made [`Json`](crate::highlight::Json) and
+    /// [`Toml`](crate::highlight::Toml) highlighters are available with the
+    /// `highlight` feature.
+    pub fn highlight<H>(mut self, highlighter: H) -> Self
+    where
+        H: Highlight + 'static,
+    {
+        self.highlight = Some(Box::new(highlighter));
+        self
+    }
+
     /// Sets a validation callback for the input that is called when the user submits.
     /// The same as [`Input::validate_on_enter`].
     pub fn validate<V>(mut self, validator: V) -> Self
@@ -144,6 +463,31 @@ impl Input {
         self
     }
 
+    /// Customizes the error message shown when the input can't be parsed into
+    /// the target type.
+    ///
+    /// By default, the [`FromStr::Err`] error message is shown. The callback
+    /// receives the input text and the original error message.
+    ///
+    /// ```
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let port: u16 = Input::new("Port")
+    ///     .parse_error(|input, err| format!("'{input}' is not a port: {err}"))
+    ///     .interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn parse_error<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, &str) -> String + 'static,
+    {
+        self.parse_error = Some(Box::new(callback));
+        self
+    }
+
     /// Enables autocomplete suggestions for the input.
     ///
     /// Multiple autocomplete suggestion sources are supported:
@@ -153,20 +497,155 @@ impl Input {
     pub fn autocomplete<S>(mut self, source: S) -> Self
     where
         S: Suggest<Result = String> + 'static,
+        T: 'static,
     {
-        self.autocomplete = Some(Autocomplete::new(source));
+        self.set_autocomplete(Autocomplete::new(source));
         self
     }
 
-    /// Starts the prompt interaction.
-    pub fn interact<T>(&mut self) -> io::Result<T>
+    /// Enables autocomplete suggestions computed in a background thread,
+    /// which keeps typing responsive with slow sources (e.g. a database).
+    ///
+    /// Suggestions are computed once the input stops changing for the
+    /// `debounce` interval, and a spinner is shown while they're pending.
+    /// Results for outdated input are discarded.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let city: String = Input::new("City")
+    ///     .autocomplete_async(
+    ///         |query: &str| {
+    ///             // Pretend querying a slow database.
+    ///             std::thread::sleep(Duration::from_millis(500));
+    ///             vec![format!("{query}ville"), format!("{query}burg")]
+    ///         },
+    ///         Duration::from_millis(200),
+    ///     )
+    ///     .interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn autocomplete_async<S>(mut self, source: S, debounce: Duration) -> Self
     where
-        T: FromStr,
+        S: Suggest<Result = String> + Send + Sync + 'static,
+        T: 'static,
     {
+        self.set_autocomplete(Autocomplete::new_async(source, debounce));
+        self
+    }
+
+    /// The maximum visible number of autocomplete suggestions.
+    ///
+    /// If there are more suggestions, the visible ones follow the highlighted
+    /// suggestion, and the number of hidden ones is shown (`+N more`).
+    /// By default, all suggestions are displayed.
+    pub fn autocomplete_max_rows(mut self, height: usize) -> Self {
+        self.autocomplete_rows = Some(height);
+        if let Some(autocomplete) = &mut self.autocomplete {
+            autocomplete.max_rows(height);
+        }
+        self
+    }
+
+    /// Accepts only values from the autocomplete suggestions: the input (or its
+    /// token completed by the suggestions, see [`Suggest::suggest_at`]) must
+    /// exactly match a suggestion, or a highlighted suggestion is accepted.
+    ///
+    /// Free text still narrows the suggestions. An empty input and the default
+    /// value are accepted as usual.
+    pub fn autocomplete_strict(mut self) -> Self {
+        self.autocomplete_strict = true;
+        self
+    }
+
+    /// Completes the input in the shell style: `Tab` completes the longest
+    /// common prefix of the suggestions (or accepts the only one), the next
+    /// `Tab` cycles through the suggestions, and `Shift-Tab` cycles backwards.
+    pub fn autocomplete_shell_style(mut self) -> Self {
+        self.autocomplete_shell_style = true;
+        if let Some(autocomplete) = &mut self.autocomplete {
+            autocomplete.shell_style();
+        }
+        self
+    }
+
+    fn set_autocomplete(&mut self, mut autocomplete: Autocomplete<T>) {
+        if let Some(height) = self.autocomplete_rows {
+            autocomplete.max_rows(height);
+        }
+        if self.autocomplete_shell_style {
+            autocomplete.shell_style();
+        }
+        self.autocomplete = Some(autocomplete);
+    }
+
+    /// Shows the top autocomplete suggestion starting with the input after
+    /// the cursor as dimmed ghost text, fish shell style.
+    ///
+    /// At the end of the input, `Right` or `End` accepts the suggestion,
+    /// and `Alt-f` accepts its next word.
+    ///
+    /// ```
+    /// use cliclack::Input;
+    ///
+    /// # fn test() -> std::io::Result<()> {
+    /// let command: String = Input::new("Command")
+    ///     .autocomplete(vec!["git status".to_string(), "git stash".to_string()])
+    ///     .ghost_text()
+    ///     .autocomplete_popup(false)
+    ///     .interact()?;
+    /// # Ok(())
+    /// # }
+    /// # test().ok(); // Ignoring I/O runtime errors.
+    /// ```
+    pub fn ghost_text(mut self) -> Self {
+        self.ghost_text = true;
+        self
+    }
+
+    /// Shows or hides the popup with autocomplete suggestions. Default: `true`.
+    ///
+    /// Hiding the popup is useful along with [`Input::ghost_text`].
+    pub fn autocomplete_popup(mut self, show: bool) -> Self {
+        self.hide_popup = !show;
+        self
+    }
+
+    /// Accepts the ghost text, or its next word, if the ghost text is visible.
+    fn accept_ghost_text(&mut self, key: &Key) {
+        let ghost = self.input.ghost_text().to_string();
+        if ghost.is_empty() {
+            return;
+        }
+
+        let accepted: String = match key {
+            Key::ArrowRight | Key::End => ghost,
+            Key::UnknownEscSeq(chars) if chars.as_slice() == ['f'] => {
+                let spaces = ghost.chars().take_while(|c| c.is_whitespace()).count();
+                let word = ghost
+                    .chars()
+                    .skip(spaces)
+                    .take_while(|c| !c.is_whitespace())
+                    .count();
+                ghost.chars().take(spaces + word).collect()
+            }
+            _ => return,
+        };
+
+        self.input.extend(&accepted);
+        self.input.move_end();
+    }
+
+    /// Shows the default value in the placeholder before the interaction.
+    fn prepare(&mut self) {
         if self.placeholder.is_empty() {
             if let Some(default) = &self.default {
-                self.placeholder.extend(default);
-                self.placeholder.extend(" (default)");
+                self.placeholder
+                    .extend(&MESSAGES.read().unwrap().default_value(default));
 
                 if self.multiline == Multiline::Editing {
                     // The preview mode is convenient for immediate submission of the default value.
@@ -174,32 +653,118 @@ impl Input {
                 }
             }
         }
-        <Self as PromptInteraction<T>>::interact(self)
+    }
+
+    /// Takes the typed default value if the input is the untouched default.
+    fn take_default_value(&mut self) -> Option<T> {
+        if self.default.as_deref() != Some(&self.input.to_string()) {
+            return None;
+        }
+        self.default_value.take()
+    }
+
+    /// Replaces the input with the text edited in the external editor.
+    fn open_editor(&mut self) -> Option<String> {
+        let edited = crate::editor::edit(&self.input.to_string());
+        // The editor may show the cursor.
+        console::Term::stderr().hide_cursor().ok();
+
+        match edited {
+            Ok(text) => {
+                let text = match self.multiline {
+                    Multiline::Disabled => crate::editor::join_lines(&text),
+                    _ => text,
+                };
+                self.input.clear();
+                self.input.extend(&text);
+                self.input.move_end();
+                if self.multiline != Multiline::Disabled {
+                    self.multiline = Multiline::Preview;
+                }
+                None
+            }
+            Err(err) => Some(MESSAGES.read().unwrap().editor_failed(&err.to_string())),
+        }
     }
 }
 
-impl<T> PromptInteraction<T> for Input
-where
-    T: FromStr,
-{
-    fn input(&mut self) -> Option<&mut StringCursor> {
+/// The prompt interaction shared by untyped and typed inputs.
+impl<V> Input<V> {
+    fn cursor(&mut self) -> Option<&mut StringCursor> {
         if self.multiline == Multiline::Preview {
             return None;
         }
         Some(&mut self.input)
     }
 
-    fn on(&mut self, event: &Event) -> State<T> {
+    fn spinner_interval(&self) -> Option<Duration> {
+        // Animates the spinner while suggestions are pending.
+        self.autocomplete
+            .as_ref()
+            .filter(|autocomplete| autocomplete.is_pending())
+            .map(|_| Duration::from_millis(80))
+    }
+
+    /// Parses the input into the target type, converting the error into a message.
+    fn parse<T>(&self) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let input = self.input.to_string();
+
+        input.parse().map_err(|err: T::Err| {
+            let err = err.to_string();
+            match &self.parse_error {
+                Some(callback) => callback(&input, &err),
+                None if err.is_empty() => MESSAGES.read().unwrap().invalid_value_format(),
+                None => err,
+            }
+        })
+    }
+
+    /// Handles the key, submitting the typed value converted by `typed`
+    /// or the input parsed into `T`.
+    fn on_key<T>(&mut self, event: &Event, typed: fn(V) -> T) -> State<T>
+    where
+        T: FromStr + 'static,
+        T::Err: Display,
+    {
         let Event::Key(key) = event;
         let mut submit = false;
 
+        // `Ctrl-X Ctrl-E` opens the external editor (`Ctrl-E` arrives as `End`).
+        if self.editor {
+            let ctrl_x = std::mem::take(&mut self.ctrl_x);
+            match key {
+                Key::Char('\x18') => {
+                    self.ctrl_x = true;
+                    return State::Active;
+                }
+                Key::End if ctrl_x => {
+                    return match self.open_editor() {
+                        Some(err) => State::Error(err),
+                        None => State::Active,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        if self.ghost_text {
+            self.accept_ghost_text(key);
+        }
+
         if let Some(autocompletion) = &mut self.autocomplete {
-            if let Some(state) = autocompletion.on(key, &self.input.to_string()) {
+            let state = match key {
+                // Suggestions can't be highlighted in the hidden popup.
+                Key::ArrowUp | Key::ArrowDown if self.hide_popup => None,
+                _ => autocompletion.on(key, &self.input.to_string(), self.input.position()),
+            };
+            if let Some(state) = state {
                 match state {
-                    State::Submit(value) => {
-                        self.input.clear();
-                        self.input.extend(&value);
-                        self.input.move_end();
+                    State::Submit(completion) => {
+                        self.input.replace(completion.range, &completion.text);
                     }
                     State::Cancel => return State::Cancel, // Workaround for `Esc`: "cancel cancelling".
                     _ => {}
@@ -235,9 +800,9 @@ where
 
         if submit && self.input.is_empty() {
             if let Some(default) = &self.default {
-                self.input.extend(default);
+                self.input.extend_unlimited(default);
             } else if self.input_required {
-                return State::Error("Input required".to_string());
+                return State::Error(MESSAGES.read().unwrap().input_required());
             }
         }
 
@@ -246,54 +811,406 @@ where
                 return State::Error(err);
             }
 
-            if self.input.to_string().parse::<T>().is_err() {
-                return State::Error("Invalid value format".to_string());
+            if let Err(err) = self.parse::<T>() {
+                return State::Error(err);
             }
         }
 
         if submit {
+            if !self.input.is_complete() && !self.input.is_empty() {
+                return State::Error(MESSAGES.read().unwrap().incomplete_input());
+            }
+
+            let input = self.input.to_string();
+            let is_default = self.default.as_deref() == Some(&input);
+            if let Some(autocomplete) = &self.autocomplete {
+                if self.autocomplete_strict
+                    && !input.is_empty()
+                    && !is_default
+                    && !autocomplete.is_known(&input)
+                {
+                    return State::Error(MESSAGES.read().unwrap().unknown_value());
+                }
+            }
+
             if let Some(validator) = &self.validate_on_enter {
-                if let Err(err) = validator(&self.input.to_string()) {
+                if let Err(err) = validator(&input) {
                     return State::Error(err);
                 }
             }
 
-            match self.input.to_string().parse::<T>() {
+            if let Some(value) = self.take_default_value() {
+                return State::Submit(typed(value));
+            }
+
+            if let Some(value) = self
+                .autocomplete
+                .as_mut()
+                .and_then(|autocomplete| autocomplete.take_value(&input))
+            {
+                return State::Submit(typed(value));
+            }
+
+            match self.parse::<T>() {
                 Ok(value) => return State::Submit(value),
-                Err(_) => return State::Error("Invalid value format".to_string()),
+                Err(err) => return State::Error(err),
             }
         }
 
         State::Active
     }
 
-    fn render(&mut self, state: &State<T>) -> String {
+    fn render_state<T>(&mut self, state: &State<T>) -> String {
         let theme = THEME.read().unwrap();
+        let messages = MESSAGES.read().unwrap();
+
+        // The visible lines follow the cursor.
+        let line = self.input.cursor_line();
+        let total = self.input.line_count();
+        if line < self.view.start {
+            self.view.start = line;
+        } else if line >= self.view.start.saturating_add(self.view.height) {
+            self.view.start = line + 1 - self.view.height;
+        }
+        self.view.start = self
+            .view
+            .start
+            .min(total.saturating_sub(self.view.height.min(total)));
+
+        if let Some(highlight) = &self.highlight {
+            let spans = highlight.highlight(&self.input.to_string());
+            self.input.set_highlights(spans);
+        }
+
+        if let Some(autocomplete) = &mut self.autocomplete {
+            autocomplete.poll();
+        }
+
+        let ghost = match &self.autocomplete {
+            Some(autocomplete) if self.ghost_text && self.input.current().is_none() => {
+                autocomplete.ghost(&self.input.to_string())
+            }
+            _ => None,
+        };
+        self.input.set_ghost_text(ghost.unwrap_or_default());
 
         let part1 = theme.format_header(&state.into(), &self.prompt);
-        let part2 = if self.input.is_empty() {
-            theme.format_placeholder(&state.into(), &self.placeholder)
-        } else {
-            theme.format_input(&state.into(), &self.input)
+        // The mask template is shown instead of an empty placeholder.
+        let part2 =
+            if self.input.is_empty() && !(self.input.is_masked() && self.placeholder.is_empty()) {
+                theme.format_placeholder(&state.into(), &self.placeholder)
+            } else {
+                theme.format_input_window(
+                    &state.into(),
+                    &self.input,
+                    self.view.start,
+                    self.view.height,
+                    self.line_numbers,
+                )
+            };
+        let counter = match self.input.max_len() {
+            Some(max_len) => theme.format_counter(&state.into(), self.input.len(), max_len),
+            None => String::new(),
         };
         let part3 = if self.autocomplete.is_some() {
-            theme.format_footer_for_autocomplete(&state.into(), "")
+            theme.format_footer_for_autocomplete(&state.into(), &counter)
         } else {
-            theme.format_footer_with_message(
-                &state.into(),
-                match self.multiline {
-                    Multiline::Editing => "[Esc](Preview)",
-                    Multiline::Preview => "[Enter](Submit)",
-                    _ => "",
-                },
-            )
-        };
-        let part4 = if let Some(autocomplete) = &self.autocomplete {
-            autocomplete.render(state)
-        } else {
-            String::new()
+            let hint = match self.multiline {
+                Multiline::Editing if self.editor => format!(
+                    "{} {}",
+                    messages.multiline_editing_hint(),
+                    messages.multiline_editor_hint()
+                ),
+                Multiline::Editing => messages.multiline_editing_hint(),
+                Multiline::Preview => messages.multiline_preview_hint(),
+                _ => String::new(),
+            };
+            let position = match self.view.height {
+                usize::MAX => String::new(),
+                _ => messages.line_position(line + 1, total),
+            };
+            let message = [hint, position, counter]
+                .into_iter()
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            theme.format_footer_with_message(&state.into(), &message)
         };
+        let part4 =
+            if let Some(autocomplete) = self.autocomplete.as_ref().filter(|_| !self.hide_popup) {
+                autocomplete.render(state)
+            } else {
+                String::new()
+            };
 
         part1 + &part2 + &part3 + &part4
     }
 }
+
+impl<T> PromptInteraction<T> for Input
+where
+    T: FromStr + 'static,
+    T::Err: Display,
+{
+    fn input(&mut self) -> Option<&mut StringCursor> {
+        self.cursor()
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.spinner_interval()
+    }
+
+    fn on(&mut self, event: &Event) -> State<T> {
+        self.on_key(event, |untyped| match untyped {})
+    }
+
+    fn render(&mut self, state: &State<T>) -> String {
+        self.render_state(state)
+    }
+}
+
+impl<T> PromptInteraction<T> for Input<T>
+where
+    T: FromStr + 'static,
+    T::Err: Display,
+{
+    fn input(&mut self) -> Option<&mut StringCursor> {
+        self.cursor()
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.spinner_interval()
+    }
+
+    fn on(&mut self, event: &Event) -> State<T> {
+        self.on_key(event, |value| value)
+    }
+
+    fn render(&mut self, state: &State<T>) -> String {
+        self.render_state(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Types the text like the interaction loop does.
+    fn write<T>(prompt: &mut impl PromptInteraction<T>, text: &str) {
+        for chr in text.chars() {
+            if let Some(cursor) = prompt.input() {
+                cursor.insert(chr);
+            }
+            prompt.on(&Event::Key(Key::Char(chr)));
+        }
+    }
+
+    /// Types the text and presses `Enter`.
+    fn enter<T>(prompt: &mut impl PromptInteraction<T>, text: &str) -> State<T> {
+        write(prompt, text);
+        prompt.on(&Event::Key(Key::Enter))
+    }
+
+    fn press<T>(prompt: &mut impl PromptInteraction<T>, key: Key) -> State<T> {
+        prompt.on(&Event::Key(key))
+    }
+
+    /// Formats differently from what it parses, so it can't be re-parsed.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Token(u32);
+
+    impl Display for Token {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "token #{}", self.0)
+        }
+    }
+
+    impl FromStr for Token {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, String> {
+            s.parse()
+                .map(Token)
+                .map_err(|_| format!("not a token: {s}"))
+        }
+    }
+
+    #[test]
+    fn default_value() {
+        let mut input = Input::new("Token").default_value(Token(7));
+        assert!(matches!(enter(&mut input, ""), State::Submit(Token(7))));
+
+        let mut input = Input::new("Token").default_value(Token(7));
+        assert!(matches!(enter(&mut input, "42"), State::Submit(Token(42))));
+
+        let mut input = Input::new("Port").typed::<u16>().default_value(8080);
+        assert!(matches!(enter(&mut input, ""), State::Submit(8080)));
+    }
+
+    #[test]
+    fn default_input() {
+        let mut input = Input::new("Port").default_input("8080");
+        assert!(matches!(enter::<u16>(&mut input, ""), State::Submit(8080)));
+
+        // The default value is replaced by the default text.
+        let mut input = Input::new("Port").default_value(1).default_input("2");
+        assert!(matches!(enter(&mut input, ""), State::Submit(2)));
+    }
+
+    #[test]
+    fn max_len_default() {
+        // The default value longer than the limit isn't truncated.
+        let mut input = Input::new("Name").max_len(3).default_input("abcdef");
+        assert!(matches!(enter::<String>(&mut input, ""), State::Submit(s) if s == "abcdef"));
+
+        // Unlike the typed text.
+        let mut input = Input::new("Name").max_len(3).default_input("abcdef");
+        assert!(matches!(enter::<String>(&mut input, "xyzw"), State::Submit(s) if s == "xyz"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        // The error of the target type is shown as is.
+        let mut input = crate::input("Port");
+        assert!(matches!(
+            enter::<u16>(&mut input, "99999"),
+            State::Error(err) if err == "number too large to fit in target type"
+        ));
+
+        // A generic message if the error has none.
+        #[derive(Debug)]
+        struct Flag;
+        impl FromStr for Flag {
+            type Err = String;
+            fn from_str(_: &str) -> Result<Self, String> {
+                Err(String::new())
+            }
+        }
+        let mut input = crate::input("Flag");
+        let message = MESSAGES.read().unwrap().invalid_value_format();
+        assert!(matches!(enter::<Flag>(&mut input, "x"), State::Error(err) if err == message));
+
+        // The message is customized with the input and the original error.
+        let mut input = crate::input("Token").parse_error(|input, err| format!("{input}: {err}"));
+        assert!(matches!(
+            enter::<Token>(&mut input, "x"),
+            State::Error(err) if err == "x: not a token: x"
+        ));
+    }
+
+    fn tokens() -> Vec<Suggestion<Token>> {
+        vec![
+            Suggestion::new("seven").hint("7").value(Token(7)),
+            Suggestion::new("eight").hint("8").value(Token(8)),
+        ]
+    }
+
+    #[test]
+    fn autocomplete_value() {
+        // The value of the highlighted suggestion is returned without parsing.
+        let mut input = Input::new("Token").autocomplete_items(tokens());
+        write(&mut input, "eight");
+        press(&mut input, Key::ArrowDown);
+        assert!(matches!(enter(&mut input, ""), State::Submit(Token(8))));
+
+        // The suggestion accepted by `Tab` keeps the value until submitted.
+        let mut input = Input::new("Token").autocomplete_items(tokens());
+        write(&mut input, "sev");
+        press(&mut input, Key::ArrowDown);
+        press(&mut input, Key::Tab);
+        assert_eq!(input.input.to_string(), "seven");
+        assert!(matches!(enter(&mut input, ""), State::Submit(Token(7))));
+
+        // The edited suggestion is parsed.
+        let mut input = Input::new("Token").autocomplete_items(tokens());
+        write(&mut input, "sev");
+        press(&mut input, Key::ArrowDown);
+        press(&mut input, Key::Tab);
+        input.input.clear();
+        assert!(matches!(enter(&mut input, "9"), State::Submit(Token(9))));
+    }
+
+    #[test]
+    fn autocomplete_typed() {
+        // Suggestions without values are parsed into the type fixed beforehand.
+        let mut input = Input::new("Port")
+            .typed::<u16>()
+            .autocomplete(vec!["80".to_string(), "443".to_string()]);
+        write(&mut input, "80");
+        press(&mut input, Key::ArrowDown);
+        assert!(matches!(enter(&mut input, ""), State::Submit(80)));
+
+        // The autocompletion set before fixing the type is kept.
+        let mut input = Input::new("Port")
+            .autocomplete(vec!["80".to_string(), "443".to_string()])
+            .default_value(8080u16);
+        write(&mut input, "80");
+        press(&mut input, Key::ArrowDown);
+        assert!(matches!(enter(&mut input, ""), State::Submit(80)));
+    }
+
+    #[test]
+    fn autocomplete_strict() {
+        let strict = || {
+            Input::new("Language")
+                .autocomplete(vec!["rust".to_string(), "ruby".to_string()])
+                .autocomplete_strict()
+        };
+
+        // The input exactly matching a suggestion is accepted.
+        let mut input = strict();
+        assert!(matches!(enter::<String>(&mut input, "ruby"), State::Submit(s) if s == "ruby"));
+
+        // Free text is rejected, even a prefix or a differently cased suggestion.
+        let mut input = strict();
+        assert!(matches!(enter::<String>(&mut input, "ru"), State::Error(_)));
+        let mut input = strict();
+        assert!(matches!(
+            enter::<String>(&mut input, "Rust"),
+            State::Error(_)
+        ));
+
+        // The highlighted suggestion is accepted instead of the free text.
+        let mut input = strict();
+        assert!(matches!(enter::<String>(&mut input, "ru"), State::Error(_)));
+        press::<String>(&mut input, Key::ArrowDown);
+        assert!(matches!(enter::<String>(&mut input, ""), State::Submit(s) if s == "rust"));
+
+        // An empty input and the default value are accepted as usual.
+        let mut input = strict().required(false);
+        assert!(matches!(enter::<String>(&mut input, ""), State::Submit(s) if s.is_empty()));
+        let mut input = strict().default_input("go");
+        assert!(matches!(enter::<String>(&mut input, ""), State::Submit(s) if s == "go"));
+    }
+
+    #[test]
+    fn ghost_text() {
+        let mut input = Input::new("Command")
+            .autocomplete(|_: &str| vec!["git status --short".to_string()])
+            .ghost_text();
+        write::<String>(&mut input, "git s");
+
+        // The ghost text is updated on rendering.
+        PromptInteraction::<String>::render(&mut input, &State::Active);
+        assert_eq!(input.input.ghost_text(), "tatus --short");
+
+        // `Alt-f` accepts the next word of the ghost text.
+        press::<String>(&mut input, Key::UnknownEscSeq(vec!['f']));
+        assert_eq!(input.input.to_string(), "git status");
+
+        PromptInteraction::<String>::render(&mut input, &State::Active);
+        assert_eq!(input.input.ghost_text(), " --short");
+        press::<String>(&mut input, Key::ArrowRight);
+        assert_eq!(input.input.to_string(), "git status --short");
+
+        // Other keys don't accept it.
+        let mut input = Input::new("Command")
+            .autocomplete(|_: &str| vec!["git status".to_string()])
+            .ghost_text();
+        write::<String>(&mut input, "git");
+        PromptInteraction::<String>::render(&mut input, &State::Active);
+        press::<String>(&mut input, Key::ArrowLeft);
+        assert_eq!(input.input.to_string(), "git");
+    }
+}
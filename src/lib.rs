@@ -48,7 +48,8 @@
 //! The input prompt accepts a single line (or multiple lines) of text
 //! trying to parse it into a target type.
 //!
-//! Multiline editing can be enabled by [`Input::multiline`].
+//! Multiline editing can be enabled by [`Input::multiline`], and
+//! syntax highlighting by [`Input::highlight`].
 //!
 //! ```
 //! use cliclack::input;
@@ -145,6 +146,11 @@
 //! typing enabled by [`Select::filter_mode`] and [`MultiSelect::filter_mode`]
 //! respectively.
 //!
+//! Items are ranked by the Jaro-Winkler similarity by default. Prefix,
+//! substring and fzf-like subsequence [`matcher`]s can be picked with
+//! [`Select::filter_matcher`] and [`MultiSelect::filter_matcher`], or for
+//! autocomplete suggestions with [`matcher::Matched`].
+//!
 //! ## Input Autocomplete
 //!
 //! The [`Input::autocomplete`] method enables autocompletion of the input text by suggestions.
@@ -170,6 +176,38 @@
 //!
 //! ```
 //!
+//! [`path::PathCompletion`] suggests filesystem paths completing the common
+//! prefix on `Tab`, and the [`path`] module provides path validators.
+//!
+//! A source overriding [`Suggest::suggest_at`] completes only the word under
+//! the cursor, e.g. a subcommand, then a flag, then a file of a command line.
+//!
+//! Slow suggestion sources can be run in the background with
+//! [`Input::autocomplete_async`], and [`Input::ghost_text`] shows the top
+//! suggestion inline.
+//!
+//! ## Derive
+//!
+//! With the `derive` feature enabled, `#[derive(Prompt)]` generates prompts
+//! for all fields of a struct, see [`Prompt`](trait@Prompt).
+//!
+//! ```ignore
+//! #[derive(cliclack::Prompt)]
+//! struct Config {
+//!     #[prompt("Project name", placeholder = "my-app")]
+//!     name: String,
+//!     #[prompt("Install dependencies?", default = true)]
+//!     install: bool,
+//! }
+//!
+//! let config = Config::interact()?;
+//! ```
+//!
+//! ## Clap
+//!
+//! With the `clap` feature enabled, [`clap::parse`] prompts for the required
+//! arguments which were not supplied on the command line.
+//!
 //! ## Spinner
 //!
 //! ```
@@ -283,22 +321,57 @@
 //! ```bash
 //! cargo run --example theme
 //! ```
+//!
+//! ## Localization
+//!
+//! Built-in messages (e.g. "Input required" or "Yes"/"No" labels) can be
+//! translated via the [`Messages`] trait.
+//!
+//! ```
+//! use cliclack::{set_messages, Messages};
+//!
+//! struct German;
+//!
+//! impl Messages for German {
+//!     fn input_required(&self) -> String {
+//!         "Eingabe erforderlich".into()
+//!     }
+//! }
+//!
+//! set_messages(German);
+//! ```
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs, unused_qualifications)]
 
 mod autocomplete;
+#[cfg(feature = "clap")]
+pub mod clap;
 mod confirm;
+#[cfg(feature = "chrono")]
+mod date;
+#[cfg(feature = "derive")]
+mod derive;
+mod duration;
+mod editor;
+mod file_picker;
 mod filter;
+pub mod highlight;
 mod input;
+pub mod matcher;
+mod messages;
 mod multiprogress;
 mod multiselect;
+mod number;
 mod password;
+pub mod path;
 mod progress;
 mod prompt;
 mod select;
 mod suggest;
 mod theme;
+#[cfg(feature = "chrono")]
+mod time;
 mod validate;
 mod view;
 
@@ -312,17 +385,34 @@ use theme::THEME;
 pub use theme::{reset_theme, set_theme, termwrap, Theme, ThemeState};
 // 🎨 Re-export for some `Theme` trait methods.
 pub use prompt::cursor::StringCursor;
+// 🌐 Export of the messages API.
+pub use messages::{reset_messages, set_messages, Messages};
 
 pub use confirm::Confirm;
-pub use input::Input;
+#[cfg(feature = "chrono")]
+pub use date::DatePicker;
+pub use duration::DurationInput;
+pub use file_picker::FilePicker;
+pub use input::{Input, Masked, Untyped};
 pub use multiprogress::MultiProgress;
 pub use multiselect::MultiSelect;
+pub use number::{Number, Numeric};
 pub use password::Password;
 pub use progress::ProgressBar;
 pub use select::Select;
-pub use suggest::Suggest;
+pub use suggest::{Suggest, Suggestion};
+#[cfg(feature = "chrono")]
+pub use time::TimePicker;
 pub use validate::Validate;
 
+#[cfg(feature = "derive")]
+pub use cliclack_derive::Prompt;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use derive::__private;
+#[cfg(feature = "derive")]
+pub use derive::{Choices, Prompt};
+
 fn term_write(line: impl Display) -> io::Result<()> {
     Term::stderr().write_str(line.to_string().as_str())
 }
@@ -370,6 +460,17 @@ pub fn input(prompt: impl Display) -> Input {
     Input::new(prompt)
 }
 
+/// Constructs a new [`Number`] prompt.
+///
+/// See [`Number`] for chainable methods.
+pub fn number<T>(prompt: impl Display) -> Number<T>
+where
+    T: Numeric,
+    T::Err: Display,
+{
+    Number::new(prompt)
+}
+
 /// Constructs a new [`Password`] prompt.
 ///
 /// See [`Password`] for chainable methods.
@@ -398,6 +499,36 @@ pub fn confirm(prompt: impl Display) -> Confirm {
     Confirm::new(prompt)
 }
 
+/// Constructs a new [`DatePicker`] prompt (requires the `chrono` feature).
+///
+/// See [`DatePicker`] for chainable methods.
+#[cfg(feature = "chrono")]
+pub fn date(prompt: impl Display) -> DatePicker {
+    DatePicker::new(prompt)
+}
+
+/// Constructs a new [`TimePicker`] prompt (requires the `chrono` feature).
+///
+/// See [`TimePicker`] for chainable methods.
+#[cfg(feature = "chrono")]
+pub fn time(prompt: impl Display) -> TimePicker {
+    TimePicker::new(prompt)
+}
+
+/// Constructs a new [`DurationInput`] prompt.
+///
+/// See [`DurationInput`] for chainable methods.
+pub fn duration(prompt: impl Display) -> DurationInput {
+    DurationInput::new(prompt)
+}
+
+/// Constructs a new [`FilePicker`] prompt.
+///
+/// See [`FilePicker`] for chainable methods.
+pub fn file_picker(prompt: impl Display) -> FilePicker {
+    FilePicker::new(prompt)
+}
+
 /// Constructs a new [`ProgressBar::with_spinner_template`] prompt.
 ///
 /// See [`ProgressBar`] for chainable methods.
@@ -0,0 +1,1015 @@
+//! Matching algorithms ranking items against a query: in the filter mode of
+//! selection prompts, and in autocomplete suggestions.
+//!
+//! ```
+//! use cliclack::{input, matcher::{Matched, Prefix}, select};
+//!
+//! # fn test() -> std::io::Result<()> {
+//! let language: String = input("Pick a language")
+//!     .autocomplete(Matched::new(
+//!         vec!["go".to_string(), "google-cloud".to_string()],
+//!         Prefix::new(),
+//!     ))
+//!     .interact()?;
+//!
+//! let color = select("Pick a color")
+//!     .item("red", "Red", "")
+//!     .item("green", "Green", "")
+//!     .filter_matcher(Prefix::new().case_sensitive(true))
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+use crate::{Suggest, Suggestion};
+
+/// Scores how well a label matches a query.
+///
+/// Built-in matchers are [`Prefix`], [`Substring`], [`Subsequence`]
+/// and [`JaroWinkler`] (the default one).
+pub trait Matcher {
+    /// Returns the score of the label matching the non-empty query from
+    /// `0.0` to `1.0` (the exact match), or `None` if the label doesn't match.
+    fn score(&self, label: &str, query: &str) -> Option<f64>;
+
+    /// Returns the character indices of the label matching the query,
+    /// highlighted in the list.
+    ///
+    /// By default, the first case-insensitive occurrence of each query word
+    /// is highlighted, or otherwise its characters in order.
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        match_positions(label, query)
+    }
+
+    /// Returns `true` if only labels containing the query characters in order
+    /// (case-insensitively) can match, which allows narrowing the matches
+    /// incrementally while the query grows. Default: `false`.
+    fn subsequence_only(&self) -> bool {
+        false
+    }
+
+    /// Returns the maximum score of a label of the length for the query of
+    /// the length (both in characters). It must not increase with the label
+    /// length. Allows skipping labels which can't make it to the top
+    /// (see [`Matched::limit`]). Default: `1.0`.
+    fn max_score(&self, _label_len: usize, _query_len: usize) -> f64 {
+        1.0
+    }
+
+    /// Returns `true` if the matcher scores the lowercased label and query
+    /// the same as the original ones, which lets [`Matched`] and the filter
+    /// mode pass the labels lowercased once in advance. Default: `false`.
+    fn ignores_case(&self) -> bool {
+        false
+    }
+}
+
+/// Options shared by the built-in matchers.
+#[derive(Default, Clone, Copy)]
+struct Options {
+    threshold: f64,
+    case_sensitive: bool,
+}
+
+impl Options {
+    /// Returns the characters of the text, lowercased if case-insensitive.
+    fn chars(self, text: &str) -> impl Iterator<Item = char> + '_ {
+        text.chars().map(move |c| match self.case_sensitive {
+            true => c,
+            false => lowercase(c),
+        })
+    }
+
+    /// Returns the text lowercased if case-insensitive, borrowing it if it's
+    /// lowercase already.
+    fn normalize(self, text: &str) -> Cow<'_, str> {
+        match !self.case_sensitive && text.chars().any(|c| lowercase(c) != c) {
+            true => self.chars(text).collect(),
+            false => Cow::Borrowed(text),
+        }
+    }
+
+    /// Filters out the score not exceeding the threshold.
+    fn filter(&self, score: f64) -> Option<f64> {
+        (score > self.threshold).then_some(score)
+    }
+}
+
+macro_rules! impl_options {
+    ($($matcher:ident),*) => {
+        $(impl $matcher {
+            /// Creates a new case-insensitive matcher.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Matches only labels scoring above the threshold (from `0.0` to `1.0`).
+            pub fn threshold(mut self, threshold: f64) -> Self {
+                self.options.threshold = threshold;
+                self
+            }
+
+            /// Matches case-sensitively. Default: `false`.
+            pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+                self.options.case_sensitive = case_sensitive;
+                self
+            }
+        })*
+    };
+}
+
+impl_options!(Prefix, Substring, Subsequence, JaroWinkler);
+
+/// Matches labels starting with the query, shorter labels first.
+#[derive(Default, Clone, Copy)]
+pub struct Prefix {
+    options: Options,
+}
+
+impl Matcher for Prefix {
+    fn subsequence_only(&self) -> bool {
+        true
+    }
+
+    fn ignores_case(&self) -> bool {
+        !self.options.case_sensitive
+    }
+
+    fn max_score(&self, label_len: usize, query_len: usize) -> f64 {
+        query_len as f64 / label_len.max(1) as f64
+    }
+
+    fn score(&self, label: &str, query: &str) -> Option<f64> {
+        let mut label = self.options.chars(label);
+        let mut len = 0;
+        for chr in self.options.chars(query) {
+            if label.next() != Some(chr) {
+                return None;
+            }
+            len += 1;
+        }
+
+        let label_len = len + label.count();
+        self.options.filter(len as f64 / label_len.max(1) as f64)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        match self.score(label, query) {
+            Some(_) => (0..query.chars().count()).collect(),
+            None => vec![],
+        }
+    }
+}
+
+/// Matches labels containing the query, earlier occurrences and shorter
+/// labels first.
+#[derive(Default, Clone, Copy)]
+pub struct Substring {
+    options: Options,
+}
+
+impl Substring {
+    /// Returns the character index of the first occurrence of the query,
+    /// the length of the query and the length of the label.
+    fn find(&self, label: &str, query: &str) -> Option<(usize, usize, usize)> {
+        let len = query.chars().count();
+        if len == 0 {
+            return None;
+        }
+
+        let mut start = None;
+        let mut label_len = 0;
+        for (i, (byte, _)) in label.char_indices().enumerate() {
+            label_len += 1;
+            if start.is_some() {
+                continue;
+            }
+            let mut rest = self.options.chars(&label[byte..]);
+            if self
+                .options
+                .chars(query)
+                .all(|chr| rest.next() == Some(chr))
+            {
+                start = Some(i);
+            }
+        }
+
+        Some((start?, len, label_len))
+    }
+}
+
+impl Matcher for Substring {
+    fn subsequence_only(&self) -> bool {
+        true
+    }
+
+    fn ignores_case(&self) -> bool {
+        !self.options.case_sensitive
+    }
+
+    fn max_score(&self, label_len: usize, query_len: usize) -> f64 {
+        (query_len as f64 / label_len.max(1) as f64 + 1.0) / 2.0
+    }
+
+    fn score(&self, label: &str, query: &str) -> Option<f64> {
+        let (start, len, label_len) = self.find(label, query)?;
+        let coverage = len as f64 / label_len as f64;
+        let position = 1.0 / (1 + start) as f64;
+        self.options.filter((coverage + position) / 2.0)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        match self.find(label, query) {
+            Some((start, len, _)) => (start..start + len).collect(),
+            None => vec![],
+        }
+    }
+}
+
+/// Matches labels containing the query characters in order (fzf-like),
+/// preferring contiguous characters and starts of words.
+#[derive(Default, Clone, Copy)]
+pub struct Subsequence {
+    options: Options,
+}
+
+/// Buffers of the subsequence alignment reused between labels.
+#[derive(Default)]
+struct Alignment {
+    /// The characters of the label.
+    original: Vec<char>,
+    /// The normalized characters of the label and the query.
+    label: Vec<char>,
+    query: Vec<char>,
+    /// For each query character at each label index: the best score
+    /// of the query prefix, and the label index of the previous character.
+    cells: Vec<Option<(f64, usize)>>,
+}
+
+thread_local! {
+    static ALIGNMENT: RefCell<Alignment> = RefCell::new(Alignment::default());
+}
+
+impl Subsequence {
+    /// Finds the best score of the query characters in the label, and
+    /// optionally their positions.
+    fn align(&self, label: &str, query: &str, positions: Option<&mut Vec<usize>>) -> Option<f64> {
+        ALIGNMENT.with_borrow_mut(|buffers| {
+            let Alignment {
+                original,
+                label: normalized,
+                query: chars,
+                cells,
+            } = buffers;
+
+            original.clear();
+            normalized.clear();
+            for (chr, normalized_chr) in label.chars().zip(self.options.chars(label)) {
+                original.push(chr);
+                normalized.push(normalized_chr);
+            }
+            chars.clear();
+            chars.extend(self.options.chars(query));
+
+            let (len, query_len) = (normalized.len(), chars.len());
+            if query_len == 0 || query_len > len {
+                return None;
+            }
+
+            let boundary = |i: usize| {
+                i == 0
+                    || matches!(original[i - 1], ' ' | '-' | '_' | '/' | '.' | ':')
+                    || (original[i - 1].is_lowercase() && original[i].is_uppercase())
+            };
+
+            cells.clear();
+            cells.resize(query_len * len, None);
+
+            for (j, q) in chars.iter().enumerate() {
+                let (previous, row) = cells.split_at_mut(j * len);
+                let previous = &previous[previous.len().saturating_sub(len)..];
+                let row = &mut row[..len];
+                // The best score of the previous row before the index.
+                let mut best: Option<(f64, usize)> = None;
+
+                for i in j..len {
+                    if j > 0 && i > 0 {
+                        if let Some((score, _)) = previous[i - 1] {
+                            if best.is_none_or(|(b, _)| score > b) {
+                                best = Some((score, i - 1));
+                            }
+                        }
+                    }
+                    if normalized[i] != *q {
+                        continue;
+                    }
+
+                    // A bonus for a start of a word, or for a contiguous character.
+                    let bonus = 1.0 + if boundary(i) { 1.0 } else { 0.0 };
+                    row[i] = match j {
+                        0 => Some((bonus, 0)),
+                        _ => {
+                            let contiguous = previous[i - 1].map(|(score, _)| (score + 2.0, i - 1));
+                            let gapped = best.map(|(score, k)| (score + bonus, k));
+                            match (contiguous, gapped) {
+                                (Some(c), Some(g)) if g.0 > c.0 => Some(g),
+                                (Some(c), _) => Some(c),
+                                (None, g) => g,
+                            }
+                        }
+                    };
+                }
+            }
+
+            let last = &cells[(query_len - 1) * len..];
+            let (mut i, score) = last
+                .iter()
+                .enumerate()
+                .filter_map(|(i, cell)| cell.map(|(score, _)| (i, score)))
+                .fold(None, |best: Option<(usize, f64)>, (i, score)| match best {
+                    Some((_, b)) if b >= score => best,
+                    _ => Some((i, score)),
+                })?;
+
+            if let Some(positions) = positions {
+                positions.resize(query_len, 0);
+                for j in (0..query_len).rev() {
+                    positions[j] = i;
+                    i = cells[j * len + i].map_or(0, |(_, k)| k);
+                }
+            }
+
+            // At best, the query is found contiguous at a start of a word.
+            let max = (2 * query_len) as f64;
+            let coverage = query_len as f64 / len as f64;
+            Some(score / max * 0.9 + coverage * 0.1)
+        })
+    }
+}
+
+impl Matcher for Subsequence {
+    fn subsequence_only(&self) -> bool {
+        true
+    }
+
+    fn max_score(&self, label_len: usize, query_len: usize) -> f64 {
+        0.9 + 0.1 * query_len as f64 / label_len.max(1) as f64
+    }
+
+    fn score(&self, label: &str, query: &str) -> Option<f64> {
+        let score = self.align(label, query, None)?;
+        self.options.filter(score)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        let mut positions = vec![];
+        self.align(label, query, Some(&mut positions));
+        positions
+    }
+}
+
+/// Ranks labels by the Jaro-Winkler similarity (computed like `strsim::jaro_winkler`),
+/// with a bonus if all whitespace-separated query words appear in the label.
+///
+/// The default threshold is `0.3`: the similarity of `0.6` without the bonus.
+#[derive(Clone, Copy)]
+pub struct JaroWinkler {
+    options: Options,
+}
+
+/// Buffers of the Jaro similarity reused between labels.
+#[derive(Default)]
+struct Jaro {
+    /// The normalized characters of the label and the query.
+    label: Vec<char>,
+    query: Vec<char>,
+    /// Whether each character of the label, then of the query, is matched.
+    flags: Vec<bool>,
+}
+
+thread_local! {
+    static JARO: RefCell<Jaro> = RefCell::new(Jaro::default());
+}
+
+impl JaroWinkler {
+    /// Returns the Jaro-Winkler similarity of the label and the query, and
+    /// optionally the indices of the label characters matched by the Jaro one.
+    fn similarity(&self, label: &str, query: &str, positions: Option<&mut Vec<usize>>) -> f64 {
+        JARO.with_borrow_mut(|buffers| {
+            let Jaro {
+                label: a,
+                query: b,
+                flags,
+            } = buffers;
+
+            a.clear();
+            a.extend(self.options.chars(label));
+            b.clear();
+            b.extend(self.options.chars(query));
+
+            let (a_len, b_len) = (a.len(), b.len());
+            if a_len == 0 || b_len == 0 {
+                return (a_len == b_len) as usize as f64;
+            }
+
+            // Equal characters match if they aren't farther apart than half
+            // of the longer length, each character only once.
+            let range = (a_len.max(b_len) / 2).saturating_sub(1);
+            flags.clear();
+            flags.resize(a_len + b_len, false);
+            let (a_flags, b_flags) = flags.split_at_mut(a_len);
+            let mut matches = 0;
+
+            for (i, chr) in a.iter().enumerate() {
+                let end = (i + range + 1).min(b_len);
+                if let Some(j) =
+                    (i.saturating_sub(range)..end).find(|&j| !b_flags[j] && b[j] == *chr)
+                {
+                    a_flags[i] = true;
+                    b_flags[j] = true;
+                    matches += 1;
+                }
+            }
+            if matches == 0 {
+                return 0.0;
+            }
+
+            // Matched characters in different order are transposed.
+            let matched_a = a.iter().zip(a_flags.iter()).filter(|(_, &flag)| flag);
+            let matched_b = b.iter().zip(b_flags.iter()).filter(|(_, &flag)| flag);
+            let transpositions = matched_a
+                .zip(matched_b)
+                .filter(|((a, _), (b, _))| a != b)
+                .count()
+                / 2;
+
+            if let Some(positions) = positions {
+                positions.extend((0..a_len).filter(|&i| a_flags[i]));
+            }
+
+            let m = matches as f64;
+            let jaro =
+                (m / a_len as f64 + m / b_len as f64 + (m - transpositions as f64) / m) / 3.0;
+            if jaro <= 0.7 {
+                return jaro;
+            }
+
+            // The Winkler bonus for a common prefix of up to 4 characters.
+            let prefix = a.iter().zip(b.iter()).take(4).take_while(|(a, b)| a == b);
+            jaro + 0.1 * prefix.count() as f64 * (1.0 - jaro)
+        })
+    }
+}
+
+impl Default for JaroWinkler {
+    fn default() -> Self {
+        Self {
+            options: Options {
+                threshold: 0.3,
+                case_sensitive: false,
+            },
+        }
+    }
+}
+
+impl Matcher for JaroWinkler {
+    fn ignores_case(&self) -> bool {
+        !self.options.case_sensitive
+    }
+
+    /// The Jaro similarity of a longer label is at most `(q / l + 2) / 3`
+    /// (all query characters match), the Winkler prefix adds at most `0.1`
+    /// of the rest per query character (up to 4), and the bonus is at most `1`.
+    fn max_score(&self, label_len: usize, query_len: usize) -> f64 {
+        if label_len <= query_len {
+            return 1.0;
+        }
+        let jaro = (query_len as f64 / label_len as f64 + 2.0) / 3.0;
+        let prefix = query_len.min(4) as f64;
+        (jaro + 0.1 * prefix * (1.0 - jaro) + 1.0) / 2.0
+    }
+
+    fn score(&self, label: &str, query: &str) -> Option<f64> {
+        let similarity = self.similarity(label, query, None);
+
+        let label = self.options.normalize(label);
+        let query = self.options.normalize(query);
+        let bonus = query.split_whitespace().all(|word| label.contains(word)) as usize as f64;
+        self.options.filter((similarity + bonus) / 2.0)
+    }
+
+    /// Returns the occurrences of the query words if all of them appear in
+    /// the label (the bonus), or otherwise the label characters matched by
+    /// the Jaro similarity.
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        if self.score(label, query).is_none() {
+            return vec![];
+        }
+
+        let normalized: Vec<char> = self.options.chars(label).collect();
+        let mut positions = vec![];
+
+        for word in query.split_whitespace() {
+            let word: Vec<char> = self.options.chars(word).collect();
+            match normalized.windows(word.len()).position(|w| w == word) {
+                Some(start) => positions.extend(start..start + word.len()),
+                None => {
+                    positions.clear();
+                    self.similarity(label, query, Some(&mut positions));
+                    return positions;
+                }
+            }
+        }
+
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+}
+
+/// A list of suggestions ranked by the matcher, see
+/// [`Input::autocomplete`](crate::Input::autocomplete).
+///
+/// Built-in lists (e.g. `Vec<String>`) are ranked by [`JaroWinkler`].
+///
+/// Suitable for very large lists: normalized labels are indexed on the first
+/// query, and matches are narrowed incrementally while the query grows
+/// (except for [`JaroWinkler`]). Ranking can be limited to the top suggestions
+/// with [`Matched::limit`].
+pub struct Matched<T, M> {
+    items: Vec<T>,
+    matcher: M,
+    limit: usize,
+    index: Mutex<Option<Index>>,
+}
+
+impl<T, M: Matcher> Matched<T, M> {
+    /// Creates a list of suggestions ranked by the matcher.
+    pub fn new(items: Vec<T>, matcher: M) -> Self {
+        Self {
+            items,
+            matcher,
+            limit: usize::MAX,
+            index: Mutex::new(None),
+        }
+    }
+
+    /// Returns at most `limit` top ranked suggestions, e.g. as many as
+    /// visible in the autocomplete popup. Default: unlimited.
+    ///
+    /// A zero limit returns no suggestions.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns the top suggestions, indexing the labels on the first query.
+    fn search(&self, input: &str, label: impl Fn(&T) -> &str) -> Vec<&T> {
+        let mut index = self.index.lock().unwrap();
+        let index = index.get_or_insert_with(|| {
+            Index::new(
+                self.items
+                    .iter()
+                    .map(|item| label(item).to_string())
+                    .collect(),
+            )
+        });
+
+        index
+            .search(input, &self.matcher, self.limit)
+            .into_iter()
+            .map(|i| &self.items[i])
+            .collect()
+    }
+}
+
+impl<M: Matcher> Suggest for Matched<String, M> {
+    type Result = String;
+
+    fn suggest(&self, input: &str) -> Vec<String> {
+        self.search(input, String::as_str)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        self.matcher.positions(label, query)
+    }
+}
+
+impl<T: Clone, M: Matcher> Suggest for Matched<Suggestion<T>, M> {
+    type Result = Suggestion<T>;
+
+    fn suggest(&self, input: &str) -> Vec<Suggestion<T>> {
+        self.search(input, |s| s.label.as_str())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        self.matcher.positions(label, query)
+    }
+}
+
+/// Labels prepared for fast repeated matching of very large lists: original
+/// and lowercased labels are stored contiguously for cache-friendly scanning.
+pub(crate) struct Index {
+    /// All labels concatenated.
+    labels: String,
+    /// All lowercased labels concatenated.
+    normalized: String,
+    /// The end offsets of each label in the `labels` and `normalized` strings.
+    ends: Vec<(usize, usize)>,
+    /// The number of characters of each label.
+    lengths: Vec<usize>,
+    /// Label indices from the shortest label to the longest one.
+    by_length: Vec<usize>,
+    /// Growing queries and indices of the labels containing their characters
+    /// in order (case-insensitively), for narrowing the matches incrementally.
+    narrowed: Vec<(Vec<char>, Vec<usize>)>,
+}
+
+impl Index {
+    pub fn new(labels: Vec<String>) -> Self {
+        let mut index = Self {
+            labels: String::new(),
+            normalized: String::new(),
+            ends: Vec::with_capacity(labels.len()),
+            lengths: Vec::with_capacity(labels.len()),
+            by_length: (0..labels.len()).collect(),
+            narrowed: vec![],
+        };
+
+        for label in labels {
+            index.labels.push_str(&label);
+            index.normalized.extend(label.chars().map(lowercase));
+            index
+                .ends
+                .push((index.labels.len(), index.normalized.len()));
+            index.lengths.push(label.chars().count());
+        }
+        index.by_length.sort_by_key(|&i| index.lengths[i]);
+
+        index
+    }
+
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns the original and the lowercased label.
+    fn get(&self, i: usize) -> (&str, &str) {
+        let (start, normalized_start) = match i {
+            0 => (0, 0),
+            _ => self.ends[i - 1],
+        };
+        let (end, normalized_end) = self.ends[i];
+        (
+            &self.labels[start..end],
+            &self.normalized[normalized_start..normalized_end],
+        )
+    }
+
+    /// Returns indices of the labels containing the query characters in order
+    /// (from the shortest label), narrowing the matches of the longest previous
+    /// query which is a prefix of the query.
+    fn narrow(&mut self, query: &str) -> Vec<usize> {
+        let query: Vec<char> = query.chars().map(lowercase).collect();
+
+        while let Some((previous, _)) = self.narrowed.last() {
+            if query.starts_with(previous) {
+                break;
+            }
+            self.narrowed.pop();
+        }
+
+        let candidates = match self.narrowed.last() {
+            Some((previous, indices)) if *previous == query => return indices.clone(),
+            Some((_, indices)) => indices.clone(),
+            None => self.by_length.clone(),
+        };
+
+        let indices: Vec<usize> = candidates
+            .into_iter()
+            .filter(|&i| contains_in_order(self.get(i).1, &query))
+            .collect();
+
+        self.narrowed.push((query, indices.clone()));
+        indices
+    }
+
+    /// Returns indices of the labels matching the query ranked by the matcher,
+    /// at most `limit`. Empty or whitespace query keeps all labels in original
+    /// order.
+    pub fn search<M>(&mut self, query: &str, matcher: &M, limit: usize) -> Vec<usize>
+    where
+        M: Matcher + ?Sized,
+    {
+        if limit == 0 {
+            return vec![];
+        }
+        if query.trim().is_empty() {
+            return (0..self.len()).take(limit).collect();
+        }
+
+        let candidates = match matcher.subsequence_only() {
+            true => self.narrow(query),
+            false => self.by_length.clone(),
+        };
+
+        // Higher scores first, then the original order.
+        let order = |a: &(f64, usize), b: &(f64, usize)| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1));
+        let query_len = query.chars().count();
+        // Case-insensitive matchers get the prepared lowercased labels.
+        let ignores_case = matcher.ignores_case();
+        let normalized_query: String = query.chars().map(lowercase).collect();
+        let mut scored: Vec<(f64, usize)> = vec![];
+        // The lowest score of the top labels found so far.
+        let mut lowest = f64::NEG_INFINITY;
+
+        for i in candidates {
+            // Longer labels can't make it to the top either.
+            if matcher.max_score(self.lengths[i], query_len) < lowest {
+                break;
+            }
+            let score = match ignores_case {
+                true => matcher.score(self.get(i).1, &normalized_query),
+                false => matcher.score(self.get(i).0, query),
+            };
+            if let Some(score) = score {
+                scored.push((score, i));
+            }
+            if scored.len() >= limit.saturating_mul(2) {
+                scored.select_nth_unstable_by(limit - 1, order);
+                scored.truncate(limit);
+                lowest = scored[limit - 1].0;
+            }
+        }
+
+        if scored.len() > limit {
+            scored.select_nth_unstable_by(limit, order);
+            scored.truncate(limit);
+        }
+        scored.sort_unstable_by(order);
+
+        scored.into_iter().map(|(_, i)| i).collect()
+    }
+}
+
+/// Returns `true` if the text contains the characters in order.
+fn contains_in_order(text: &str, chars: &[char]) -> bool {
+    let mut rest = text;
+    for chr in chars {
+        match rest.find(*chr) {
+            Some(i) => rest = &rest[i + chr.len_utf8()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Ranks items by the score of their labels matching the query.
+///
+/// Empty or whitespace query keeps all items in original order. Otherwise,
+/// not matching items are filtered out, and the rest is sorted by score
+/// descending. Labels are used only for scoring.
+pub(crate) fn rank<L, T, M>(items: impl Iterator<Item = (L, T)>, query: &str, matcher: &M) -> Vec<T>
+where
+    L: AsRef<str>,
+    M: Matcher + ?Sized,
+{
+    if query.trim().is_empty() {
+        return items.map(|(_, item)| item).collect();
+    }
+
+    let mut scored: Vec<_> = items
+        .filter_map(|(label, item)| Some((matcher.score(label.as_ref(), query)?, item)))
+        .collect();
+
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+fn lowercase(c: char) -> char {
+    match c.is_ascii() {
+        true => c.to_ascii_lowercase(),
+        false => c.to_lowercase().next().unwrap_or(c),
+    }
+}
+
+/// Returns the character indices of the label matching the query words:
+/// the first case-insensitive occurrence of each word, or otherwise its
+/// characters in order (a subsequence). Words not found are ignored.
+pub(crate) fn match_positions(label: &str, query: &str) -> Vec<usize> {
+    let label: Vec<char> = label.chars().map(lowercase).collect();
+    let mut positions = vec![];
+
+    for word in query.split_whitespace() {
+        let word: Vec<char> = word.chars().map(lowercase).collect();
+
+        if let Some(start) = label.windows(word.len()).position(|w| w == word) {
+            positions.extend(start..start + word.len());
+            continue;
+        }
+
+        let mut subsequence = vec![];
+        let mut chars = word.iter().peekable();
+        for (i, chr) in label.iter().enumerate() {
+            if chars.peek() == Some(&chr) {
+                subsequence.push(i);
+                chars.next();
+            }
+        }
+        if chars.peek().is_none() {
+            positions.extend(subsequence);
+        }
+    }
+
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ranked<M: Matcher>(matcher: M, labels: &[&str], query: &str) -> Vec<String> {
+        let items = labels.iter().map(|label| (*label, label.to_string()));
+        rank(items, query, &matcher)
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(
+            match_positions("TypeScript", "script"),
+            vec![4, 5, 6, 7, 8, 9]
+        );
+        assert_eq!(match_positions("javascript", "jvs"), vec![0, 2, 4]);
+        assert_eq!(
+            match_positions("ice cream", "cream ice"),
+            vec![0, 1, 2, 4, 5, 6, 7, 8]
+        );
+        assert!(match_positions("rust", "xyz").is_empty());
+        assert!(match_positions("rust", "").is_empty());
+    }
+
+    #[test]
+    fn prefix() {
+        let labels = ["google-cloud", "Go", "cargo"];
+        assert_eq!(ranked(Prefix::new(), &labels, "go"), ["Go", "google-cloud"]);
+        assert_eq!(
+            ranked(Prefix::new().case_sensitive(true), &labels, "go"),
+            ["google-cloud"]
+        );
+        assert_eq!(Prefix::new().positions("Go", "go"), vec![0, 1]);
+    }
+
+    #[test]
+    fn substring() {
+        let labels = ["cargo", "go", "mongodb", "rust"];
+        assert_eq!(
+            ranked(Substring::new(), &labels, "go"),
+            ["go", "cargo", "mongodb"]
+        );
+        assert_eq!(Substring::new().positions("mongodb", "go"), vec![3, 4]);
+    }
+
+    #[test]
+    fn subsequence() {
+        let labels = ["profile_pic", "filter", "file_picker"];
+        assert_eq!(
+            ranked(Subsequence::new(), &labels, "fp"),
+            ["file_picker", "profile_pic"]
+        );
+        // Prefers contiguous characters and starts of words.
+        assert_eq!(Subsequence::new().positions("xfxp_fp", "fp"), vec![5, 6]);
+        assert_eq!(Subsequence::new().positions("FilePicker", "fp"), vec![0, 4]);
+        assert_eq!(Subsequence::new().score("fp", "fpx"), None);
+    }
+
+    #[test]
+    fn jaro_winkler() {
+        let labels = ["ice cream", "pizza", "sushi"];
+        assert_eq!(ranked(JaroWinkler::new(), &labels, "Cream"), ["ice cream"]);
+        assert_eq!(ranked(JaroWinkler::new(), &labels, " "), labels);
+        assert!(ranked(JaroWinkler::new().threshold(1.0), &labels, "ice").is_empty());
+
+        // The query words, or otherwise the characters matched by the similarity.
+        let matcher = JaroWinkler::new();
+        assert_eq!(
+            matcher.positions("Ice cream", "cream ice"),
+            vec![0, 1, 2, 4, 5, 6, 7, 8]
+        );
+        assert_eq!(matcher.positions("sushi", "sushy"), vec![0, 1, 2, 3]);
+        assert!(matcher.positions("pizza", "xyz").is_empty());
+    }
+
+    #[test]
+    fn jaro_winkler_similarity() {
+        let pairs = [
+            ("cheeseburger", "cheese fries"),
+            ("Friedrich Nietzsche", "Jean-Paul Sartre"),
+            ("dixon", "dicksonx"),
+            ("martha", "marhta"),
+            ("straße", "strasse"),
+            ("a", "a"),
+            ("", ""),
+            ("", "a"),
+        ];
+        let matcher = JaroWinkler::new().case_sensitive(true);
+        for (a, b) in pairs {
+            let similarity = matcher.similarity(a, b, None);
+            assert!(
+                (similarity - strsim::jaro_winkler(a, b)).abs() < 1e-12,
+                "{a} {b}"
+            );
+        }
+
+        // The bound of the score never underestimates it.
+        let labels = [
+            "serde",
+            "serde_json",
+            "async-serde-core-4211",
+            "s",
+            "json-serde",
+        ];
+        for label in labels {
+            for query in ["s", "serde", "serde js", "json-serde-core"] {
+                let score = JaroWinkler::new().score(label, query).unwrap_or(0.0);
+                let max = JaroWinkler::new().max_score(label.len(), query.len());
+                assert!(score <= max, "{label} {query}");
+            }
+        }
+    }
+
+    #[test]
+    fn index() {
+        let labels = ["serde", "serde_json", "tokio", "Serial"];
+        let mut index = Index::new(labels.iter().map(|l| l.to_string()).collect());
+        let matcher = Subsequence::new();
+
+        assert_eq!(index.search("se", &matcher, usize::MAX), [0, 3, 1]);
+        assert_eq!(index.search("ser", &matcher, 2), [0, 3]);
+        assert_eq!(index.narrowed.len(), 2);
+
+        // Narrowing restarts from the longest previous prefix.
+        assert_eq!(index.search("s", &matcher, usize::MAX), [0, 3, 1]);
+        assert_eq!(index.narrowed.len(), 1);
+        assert!(index.search("x", &matcher, usize::MAX).is_empty());
+        assert_eq!(index.search(" ", &matcher, 3), [0, 1, 2]);
+
+        // Not narrowing matchers score all labels.
+        assert_eq!(index.search("tokyo", &JaroWinkler::new(), usize::MAX), [2]);
+
+        // Case-insensitive matchers score the lowercased labels the same.
+        assert_eq!(index.search("SER", &JaroWinkler::new(), 2), [0, 3]);
+        assert_eq!(
+            index.search("Ser", &Prefix::new().case_sensitive(true), 2),
+            [3]
+        );
+
+        // No labels for the zero limit.
+        assert!(index.search("ser", &matcher, 0).is_empty());
+        assert!(index.search("", &matcher, 0).is_empty());
+        let matched = Matched::new(vec!["serde".to_string()], Prefix::new()).limit(0);
+        assert!(matched.suggest("se").is_empty());
+    }
+
+    #[test]
+    fn nan_scores() {
+        // A custom matcher's NaN scores are ranked without panicking.
+        struct Nan;
+        impl Matcher for Nan {
+            fn score(&self, label: &str, _query: &str) -> Option<f64> {
+                Some(if label == "b" { f64::NAN } else { 0.5 })
+            }
+        }
+
+        let labels = ["a", "b", "c"];
+        assert_eq!(ranked(Nan, &labels, "x").len(), 3);
+        let mut index = Index::new(labels.iter().map(|l| l.to_string()).collect());
+        assert_eq!(index.search("x", &Nan, 2).len(), 2);
+    }
+
+    #[test]
+    fn suggestion_positions() {
+        // Suggestions are highlighted by the positions of their matcher.
+        let items = vec!["rustup".to_string()];
+        assert_eq!(
+            Matched::new(items.clone(), Prefix::new()).positions("rustup", "ru"),
+            [0, 1]
+        );
+        assert_eq!(
+            Matched::new(items, Substring::new()).positions("rustup", "up"),
+            [4, 5]
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::sync::RwLock;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+/// Defines the built-in user-facing strings of the prompts. By default, the
+/// English messages are used.
+///
+/// In order to translate the messages, implement the [`Messages`] trait,
+/// and redefine the required methods:
+///
+/// ```
+/// use cliclack::{set_messages, Messages};
+///
+/// struct German;
+///
+/// impl Messages for German {
+///     fn input_required(&self) -> String {
+///         "Eingabe erforderlich".into()
+///     }
+///
+///     fn yes(&self) -> String {
+///         "Ja".into()
+///     }
+///
+///     fn no(&self) -> String {
+///         "Nein".into()
+///     }
+/// }
+///
+/// set_messages(German);
+/// ```
+pub trait Messages {
+    /// An error when an empty input is submitted.
+    fn input_required(&self) -> String {
+        "Input required".into()
+    }
+
+    /// An error when the input with a mask is submitted partially filled.
+    fn incomplete_input(&self) -> String {
+        "Incomplete input".into()
+    }
+
+    /// A fallback error when the input can't be parsed into the target type,
+    /// and the parse error has no message.
+    fn invalid_value_format(&self) -> String {
+        "Invalid value format".into()
+    }
+
+    /// An error when the input doesn't match any autocomplete suggestion.
+    fn unknown_value(&self) -> String {
+        "Not a known value".into()
+    }
+
+    /// An error when the number is less than the minimum.
+    fn value_too_small(&self, min: &str) -> String {
+        format!("Must be at least {min}")
+    }
+
+    /// An error when the number is greater than the maximum.
+    fn value_too_large(&self, max: &str) -> String {
+        format!("Must be at most {max}")
+    }
+
+    /// An error when a date out of bounds or a disabled date is submitted.
+    fn date_not_available(&self) -> String {
+        "Date is not available".into()
+    }
+
+    /// The name of the month (1-12) in the calendar title.
+    fn month_name(&self, month: u32) -> String {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        MONTHS[(month as usize + 11) % 12].into()
+    }
+
+    /// The short name of the weekday in the calendar, from `0` (Monday)
+    /// to `6` (Sunday).
+    fn weekday_short(&self, weekday: u32) -> String {
+        const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+        WEEKDAYS[weekday as usize % 7].into()
+    }
+
+    /// An error when the input can't be parsed as a duration.
+    fn invalid_duration(&self) -> String {
+        "Expected a duration like 1h30m or 90s".into()
+    }
+
+    /// The interpretation of the entered duration, e.g. `1 hour 30 minutes`.
+    fn duration(&self, duration: Duration) -> String {
+        let secs = duration.as_secs();
+        let parts = [
+            (secs / 86400, "day"),
+            (secs / 3600 % 24, "hour"),
+            (secs / 60 % 60, "minute"),
+            (secs % 60, "second"),
+            (u64::from(duration.subsec_millis()), "millisecond"),
+        ];
+
+        let text = parts
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| match value {
+                1 => format!("1 {unit}"),
+                _ => format!("{value} {unit}s"),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        match text.is_empty() {
+            true => "0 seconds".into(),
+            false => text,
+        }
+    }
+
+    /// The interpretation of the entered time of day, e.g. `2:30 PM`.
+    fn time_of_day(&self, hour: u32, minute: u32, second: Option<u32>) -> String {
+        let period = if hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+
+        match second {
+            Some(second) => format!("{hour}:{minute:02}:{second:02} {period}"),
+            None => format!("{hour}:{minute:02} {period}"),
+        }
+    }
+
+    /// An error when the path must exist but it doesn't.
+    fn path_not_found(&self) -> String {
+        "Path does not exist".into()
+    }
+
+    /// An error when the path must not exist but it does.
+    fn path_already_exists(&self) -> String {
+        "Path already exists".into()
+    }
+
+    /// An error when the path is not writable.
+    fn path_not_writable(&self) -> String {
+        "Path is not writable".into()
+    }
+
+    /// An error when the directory can't be browsed, with the I/O error.
+    fn directory_not_readable(&self, error: &str) -> String {
+        format!("Can't read the directory: {error}")
+    }
+
+    /// An error when no item matches the filter of a selection prompt.
+    fn no_items(&self) -> String {
+        "No items".into()
+    }
+
+    /// The number of suggestions not fitting into the autocomplete popup.
+    fn more_items(&self, count: usize) -> String {
+        format!("+{count} more")
+    }
+
+    /// A hint shown while autocomplete suggestions are computed in the background.
+    fn suggestions_pending(&self) -> String {
+        "Searching...".into()
+    }
+
+    /// An error when a selection prompt is started without items.
+    fn no_items_added(&self) -> String {
+        "No items added to the list".into()
+    }
+
+    /// A hint of the multiline input in the editing mode.
+    fn multiline_editing_hint(&self) -> String {
+        "[Esc](Preview)".into()
+    }
+
+    /// A hint of the multiline input in the editing mode when the external
+    /// editor is enabled.
+    fn multiline_editor_hint(&self) -> String {
+        "[Ctrl-X Ctrl-E](Editor)".into()
+    }
+
+    /// An error when the external editor fails.
+    fn editor_failed(&self, err: &str) -> String {
+        format!("Editor failed: {err}")
+    }
+
+    /// The cursor position in the multiline input with limited visible lines.
+    fn line_position(&self, line: usize, total: usize) -> String {
+        format!("line {line}/{total}")
+    }
+
+    /// A hint of the multiline input in the preview mode.
+    fn multiline_preview_hint(&self) -> String {
+        "[Enter](Submit)".into()
+    }
+
+    /// A placeholder showing the default value of the input.
+    fn default_value(&self, value: &str) -> String {
+        format!("{value} (default)")
+    }
+
+    /// The "yes" label of the confirmation prompt.
+    fn yes(&self) -> String {
+        "Yes".into()
+    }
+
+    /// The "no" label of the confirmation prompt.
+    fn no(&self) -> String {
+        "No".into()
+    }
+
+    /// A footer message when a prompt is cancelled.
+    fn operation_cancelled(&self) -> String {
+        "Operation cancelled.".into()
+    }
+
+    /// A footer message of the multiselect prompt when selected items
+    /// are filtered out.
+    fn selected_items_not_displayed(&self, count: usize) -> String {
+        format!(
+            "{count} selected item{s} not displayed",
+            s = if count > 1 { "s" } else { "" }
+        )
+    }
+}
+
+/// Default English messages.
+struct EnglishMessages;
+
+/// Using default messages from the [`Messages`] trait.
+impl Messages for EnglishMessages {}
+
+/// The global messages instance (singleton).
+///
+/// It can be set with [`set_messages`] function.
+pub(crate) static MESSAGES: Lazy<RwLock<Box<dyn Messages + Send + Sync>>> =
+    Lazy::new(|| RwLock::new(Box::new(EnglishMessages)));
+
+/// Sets the global messages, which are used by all prompts.
+///
+/// See [`reset_messages`] for returning to the default English messages.
+pub fn set_messages<M: Messages + Sync + Send + 'static>(messages: M) {
+    *MESSAGES.write().unwrap() = Box::new(messages);
+}
+
+/// Resets the global messages to the default English ones.
+pub fn reset_messages() {
+    *MESSAGES.write().unwrap() = Box::new(EnglishMessages);
+}
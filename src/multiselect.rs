@@ -7,6 +7,8 @@ use console::Key;
 use crate::view::ListView;
 use crate::{
     filter::FilteredView,
+    matcher::Matcher,
+    messages::MESSAGES,
     prompt::{
         cursor::StringCursor,
         interaction::{Event, PromptInteraction, State},
@@ -96,6 +98,20 @@ where
         self
     }
 
+    /// Enables the filter mode ranking the items with the matcher,
+    /// see [`matcher`](crate::matcher). Default: [`JaroWinkler`](crate::matcher::JaroWinkler).
+    ///
+    /// Only the visible items (and some more) are ranked, and more of them
+    /// as the list scrolls down. The default matcher scores the items on
+    /// every keystroke, while [`Subsequence`](crate::matcher::Subsequence)
+    /// and other matchers accepting only the query characters in order
+    /// narrow the matches incrementally, which suits very large lists.
+    pub fn filter_matcher(mut self, matcher: impl Matcher + 'static) -> Self {
+        self.filter.set_matcher(matcher);
+        self.filter.enable();
+        self
+    }
+
     /// The maximum visible number of items.
     ///
     /// If the number of items is greater than the maximum number of rows,
@@ -112,7 +128,7 @@ where
         if self.items.is_empty() {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
-                "No items added to the list",
+                MESSAGES.read().unwrap().no_items_added(),
             ));
         }
         if let Some(initial_values) = &self.initial_values {
@@ -131,7 +147,7 @@ impl<T: Clone> PromptInteraction<Vec<T>> for MultiSelect<T> {
     fn on(&mut self, event: &Event) -> State<Vec<T>> {
         let Event::Key(key) = event;
 
-        if let Some(state) = self.filter.on(key, &self.items) {
+        if let Some(state) = self.filter.on(key, &self.items, &self.page) {
             if self.filter.items().is_empty() || self.cursor > self.filter.items().len() - 1 {
                 self.cursor = 0;
             }
@@ -171,7 +187,7 @@ impl<T: Clone> PromptInteraction<Vec<T>> for MultiSelect<T> {
                     .collect::<Vec<_>>();
 
                 if selected_items.is_empty() && self.required {
-                    return State::Error("Input required".to_string());
+                    return State::Error(MESSAGES.read().unwrap().input_required());
                 }
 
                 return State::Submit(selected_items);
@@ -214,12 +230,13 @@ impl<T: Clone> PromptInteraction<Vec<T>> for MultiSelect<T> {
             .skip(self.page.start)
             .take(self.page.height)
         {
-            items_render.push_str(&theme.format_multiselect_item(
+            items_render.push_str(&theme.format_multiselect_item_matched(
                 &state.into(),
                 item.selected,
                 i == self.cursor,
                 &item.label,
                 &item.hint,
+                &self.filter.positions(&item.label),
             ));
         }
 
@@ -235,10 +252,10 @@ impl<T: Clone> PromptInteraction<Vec<T>> for MultiSelect<T> {
         let footer = if not_rendered_items > 0 {
             theme.format_footer_with_message(
                 &state.into(),
-                &format!(
-                    "{not_rendered_items} selected item{s} not displayed",
-                    s = if not_rendered_items > 1 { "s" } else { "" }
-                ),
+                &MESSAGES
+                    .read()
+                    .unwrap()
+                    .selected_items_not_displayed(not_rendered_items),
             )
         } else {
             theme.format_footer(&state.into())
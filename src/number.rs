@@ -0,0 +1,403 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::io;
+use std::str::FromStr;
+
+use console::Key;
+
+use crate::{
+    messages::MESSAGES,
+    prompt::{
+        cursor::StringCursor,
+        interaction::{Event, PromptInteraction, State},
+    },
+    theme::THEME,
+};
+
+/// A number type supported by the [`Number`] prompt: all primitive integer and
+/// floating point types.
+pub trait Numeric: Copy + Default + PartialOrd + Display + FromStr + 'static {
+    /// The default step (`1`).
+    fn one() -> Self;
+
+    /// Adds the step (saturating for integers).
+    fn step_up(self, step: Self) -> Self;
+
+    /// Subtracts the step (saturating for integers).
+    fn step_down(self, step: Self) -> Self;
+}
+
+macro_rules! impl_numeric_int {
+    ($($t:ty),*) => {
+        $(impl Numeric for $t {
+            fn one() -> Self {
+                1
+            }
+
+            fn step_up(self, step: Self) -> Self {
+                self.saturating_add(step)
+            }
+
+            fn step_down(self, step: Self) -> Self {
+                self.saturating_sub(step)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {
+        $(impl Numeric for $t {
+            fn one() -> Self {
+                1.0
+            }
+
+            fn step_up(self, step: Self) -> Self {
+                round_float(self + step, &[self, step])
+            }
+
+            fn step_down(self, step: Self) -> Self {
+                round_float(self - step, &[self, step])
+            }
+        })*
+    };
+}
+
+impl_numeric_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_numeric_float!(f32, f64);
+
+/// Rounds the float to the max number of decimals of the operands,
+/// so `0.1 + 0.2` becomes `0.3`.
+fn round_float<T: Numeric>(value: T, operands: &[T]) -> T {
+    let decimals = operands
+        .iter()
+        .map(|v| v.to_string().split_once('.').map_or(0, |(_, d)| d.len()))
+        .max()
+        .unwrap_or(0);
+
+    format!("{value:.decimals$}").parse().unwrap_or(value)
+}
+
+/// Whether the value is below the minimum or incomparable to it (NaN).
+fn is_below<T: PartialOrd>(value: T, min: T) -> bool {
+    !matches!(
+        value.partial_cmp(&min),
+        Some(Ordering::Greater | Ordering::Equal)
+    )
+}
+
+/// Whether the value is above the maximum or incomparable to it (NaN).
+fn is_above<T: PartialOrd>(value: T, max: T) -> bool {
+    !matches!(
+        value.partial_cmp(&max),
+        Some(Ordering::Less | Ordering::Equal)
+    )
+}
+
+/// A prompt that accepts a number: an integer or a float.
+///
+/// * Type the number, it's validated while typing.
+/// * `Up`/`Down` to increment/decrement the value by the step.
+/// * `PageUp`/`PageDown` to change the value by a larger step.
+///
+/// # Example
+///
+/// ```
+/// use cliclack::Number;
+///
+/// # fn test() -> std::io::Result<()> {
+/// let port: u16 = Number::new("Port")
+///     .min(1024)
+///     .default_value(8080)
+///     .interact()?;
+///
+/// let ratio: f64 = Number::new("Ratio")
+///     .min(0.0)
+///     .max(1.0)
+///     .step(0.1)
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// # test().ok(); // Ignoring I/O runtime errors.
+/// ```
+pub struct Number<T: Numeric> {
+    prompt: String,
+    input: StringCursor,
+    placeholder: StringCursor,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    page_step: Option<T>,
+    default: Option<T>,
+}
+
+impl<T: Numeric> Number<T>
+where
+    T::Err: Display,
+{
+    /// Creates a new number prompt.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            input: StringCursor::default(),
+            placeholder: StringCursor::default(),
+            min: None,
+            max: None,
+            step: T::one(),
+            page_step: None,
+            default: None,
+        }
+    }
+
+    /// Sets the placeholder (hint) text for the input.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder.extend(placeholder);
+        self
+    }
+
+    /// Sets the minimum allowed value (inclusive).
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum allowed value (inclusive).
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step of `Up`/`Down` keys. Default: `1`.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the step of `PageUp`/`PageDown` keys. Default: 10 steps.
+    pub fn page_step(mut self, page_step: T) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    /// Sets the default value, which is returned if no value is supplied,
+    /// and also a hint (placeholder) if one is not already set.
+    pub fn default_value(mut self, value: T) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    /// Starts the prompt interaction.
+    pub fn interact(&mut self) -> io::Result<T> {
+        if self.placeholder.is_empty() {
+            if let Some(default) = &self.default {
+                self.placeholder
+                    .extend(&MESSAGES.read().unwrap().default_value(&default.to_string()));
+            }
+        }
+        <Self as PromptInteraction<T>>::interact(self)
+    }
+
+    /// Parses the input without checking the bounds, rejecting NaN.
+    fn parse_value(&self) -> Result<T, String> {
+        let messages = MESSAGES.read().unwrap();
+
+        let value: T = self.input.to_string().parse().map_err(|err: T::Err| {
+            let err = err.to_string();
+            match err.is_empty() {
+                true => messages.invalid_value_format(),
+                false => err,
+            }
+        })?;
+
+        // NaN is not comparable even to itself.
+        match value.partial_cmp(&value) {
+            Some(_) => Ok(value),
+            None => Err(messages.invalid_value_format()),
+        }
+    }
+
+    /// Parses the input checking the bounds.
+    fn parse(&self) -> Result<T, String> {
+        let value = self.parse_value()?;
+        let messages = MESSAGES.read().unwrap();
+
+        match (self.min, self.max) {
+            (Some(min), _) if is_below(value, min) => {
+                Err(messages.value_too_small(&min.to_string()))
+            }
+            (_, Some(max)) if is_above(value, max) => {
+                Err(messages.value_too_large(&max.to_string()))
+            }
+            _ => Ok(value),
+        }
+    }
+
+    /// Changes the value by the step, or by the page step (10 steps by default).
+    ///
+    /// An out-of-range value is clamped into the bounds after stepping.
+    fn step_by(&mut self, up: bool, page: bool) {
+        let start = self
+            .parse_value()
+            .ok()
+            .or(self.default)
+            .or(self.min)
+            .unwrap_or_default();
+
+        let (step, times) = match (page, self.page_step) {
+            (true, Some(page_step)) => (page_step, 1),
+            (true, None) => (self.step, 10),
+            (false, _) => (self.step, 1),
+        };
+
+        let mut value = start;
+        for _ in 0..times {
+            value = match up {
+                true => value.step_up(step),
+                false => value.step_down(step),
+            };
+        }
+
+        if let Some(min) = self.min {
+            if is_below(value, min) {
+                value = min;
+            }
+        }
+        if let Some(max) = self.max {
+            if is_above(value, max) {
+                value = max;
+            }
+        }
+
+        self.input.clear();
+        self.input.extend(&value.to_string());
+        self.input.move_end();
+    }
+}
+
+impl<T: Numeric> PromptInteraction<T> for Number<T>
+where
+    T::Err: Display,
+{
+    fn input(&mut self) -> Option<&mut StringCursor> {
+        Some(&mut self.input)
+    }
+
+    fn on(&mut self, event: &Event) -> State<T> {
+        let Event::Key(key) = event;
+
+        match key {
+            Key::ArrowUp => self.step_by(true, false),
+            Key::ArrowDown => self.step_by(false, false),
+            Key::PageUp => self.step_by(true, true),
+            Key::PageDown => self.step_by(false, true),
+            Key::Enter => {
+                if self.input.is_empty() {
+                    return match self.default {
+                        Some(default) => {
+                            self.input.extend(&default.to_string());
+                            State::Submit(default)
+                        }
+                        None => State::Error(MESSAGES.read().unwrap().input_required()),
+                    };
+                }
+
+                return match self.parse() {
+                    Ok(value) => State::Submit(value),
+                    Err(err) => State::Error(err),
+                };
+            }
+            _ => {}
+        }
+
+        if self.input.is_empty() {
+            return State::Active;
+        }
+
+        match self.parse() {
+            Ok(_) => State::Active,
+            Err(err) => State::Error(err),
+        }
+    }
+
+    fn render(&mut self, state: &State<T>) -> String {
+        let theme = THEME.read().unwrap();
+
+        let line1 = theme.format_header(&state.into(), &self.prompt);
+        let line2 = if self.input.is_empty() {
+            theme.format_placeholder(&state.into(), &self.placeholder)
+        } else {
+            theme.format_input(&state.into(), &self.input)
+        };
+        let line3 = theme.format_footer(&state.into());
+
+        line1 + &line2 + &line3
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stepping() {
+        assert_eq!(250u8.step_up(10), 255);
+        assert_eq!(0u8.step_down(1), 0);
+        assert_eq!(0.1f64.step_up(0.2), 0.3);
+        assert_eq!(1.25f64.step_down(0.1), 1.15);
+    }
+
+    fn number<T: Numeric>(input: &str) -> Number<T>
+    where
+        T::Err: Display,
+    {
+        let mut number = Number::new("Number");
+        number.input.extend(input);
+        number
+    }
+
+    fn press<T: Numeric>(number: &mut Number<T>, key: Key) -> State<T>
+    where
+        T::Err: Display,
+    {
+        number.on(&Event::Key(key))
+    }
+
+    #[test]
+    fn bounds() {
+        let mut ratio = number::<f64>("0.5").min(0.0).max(1.0);
+        assert!(matches!(press(&mut ratio, Key::Enter), State::Submit(v) if v == 0.5));
+
+        let mut ratio = number::<f64>("1.5").min(0.0).max(1.0);
+        assert!(matches!(press(&mut ratio, Key::Enter), State::Error(_)));
+
+        let mut ratio = number::<f64>("-1").min(0.0);
+        assert!(matches!(press(&mut ratio, Key::Enter), State::Error(_)));
+
+        // NaN is rejected with or without bounds.
+        let mut ratio = number::<f64>("NaN").min(0.0).max(1.0);
+        assert!(matches!(press(&mut ratio, Key::Enter), State::Error(_)));
+        let mut ratio = number::<f64>("NaN");
+        assert!(matches!(press(&mut ratio, Key::Enter), State::Error(_)));
+    }
+
+    #[test]
+    fn step_clamping() {
+        // An out-of-range value is clamped, not replaced by the default.
+        let mut port = number::<u32>("99999").max(9000).default_value(80);
+        press(&mut port, Key::ArrowDown);
+        assert_eq!(port.input.to_string(), "9000");
+
+        let mut port = number::<i32>("-5").min(1024).default_value(8080);
+        press(&mut port, Key::ArrowUp);
+        assert_eq!(port.input.to_string(), "1024");
+
+        // The default value is the start if the input is not a number.
+        let mut port = number::<u16>("").default_value(80);
+        press(&mut port, Key::PageUp);
+        assert_eq!(port.input.to_string(), "90");
+
+        let mut ratio = number::<f64>("0.95").max(1.0).step(0.1);
+        press(&mut ratio, Key::ArrowUp);
+        assert_eq!(ratio.input.to_string(), "1");
+    }
+}
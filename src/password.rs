@@ -4,6 +4,7 @@ use std::io;
 use console::Key;
 
 use crate::{
+    messages::MESSAGES,
     prompt::{
         cursor::StringCursor,
         interaction::{Event, PromptInteraction, State},
@@ -41,6 +42,13 @@ impl Password {
         self
     }
 
+    /// Limits the password length in characters, and shows a live counter
+    /// (like `12/64`) in the footer revealing only the length.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.input.set_max_len(max_len);
+        self
+    }
+
     /// Allows an empty password (which is not allowed by default).
     pub fn allow_empty(mut self) -> Self {
         self.allow_empty = true;
@@ -109,7 +117,7 @@ impl PromptInteraction<String> for Password {
 
         if *key == Key::Enter {
             if self.input.is_empty() && !self.allow_empty {
-                return State::Error("Input required".to_string());
+                return State::Error(MESSAGES.read().unwrap().input_required());
             }
 
             if let Some(validator) = &self.validate_on_enter {
@@ -136,7 +144,11 @@ impl PromptInteraction<String> for Password {
 
         let line1 = theme.format_header(&state.into(), &self.prompt);
         let line2 = theme.format_input(&state.into(), &masked);
-        let line3 = theme.format_footer(&state.into());
+        let counter = match self.input.max_len() {
+            Some(max_len) => theme.format_counter(&state.into(), self.input.len(), max_len),
+            None => String::new(),
+        };
+        let line3 = theme.format_footer_with_message(&state.into(), &counter);
 
         line1 + &line2 + &line3
     }
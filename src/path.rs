@@ -0,0 +1,286 @@
+//! Filesystem path completion and validation for [`Input`](crate::Input).
+//!
+//! ```
+//! use cliclack::{input, path};
+//!
+//! # fn test() -> std::io::Result<()> {
+//! let config: String = input("Config file")
+//!     .autocomplete(path::PathCompletion::new().extensions(["toml"]))
+//!     .validate(path::exists())
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::{messages::MESSAGES, suggest::common_prefix, Suggest};
+
+/// Expands the leading `~` into the home directory.
+pub fn expand_tilde(path: &str) -> PathBuf {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+
+    match (path.strip_prefix('~'), home) {
+        (Some(""), Some(home)) => PathBuf::from(home),
+        (Some(rest), Some(home)) if rest.starts_with('/') => {
+            PathBuf::from(home).join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+/// A source of filesystem path suggestions for [`Input::autocomplete`](crate::Input::autocomplete).
+///
+/// * Lists entries of the directory typed so far matching the typed prefix.
+/// * Appends `/` to directories.
+/// * Expands `~` into the home directory.
+/// * Shows hidden entries only if the typed prefix starts with `.`.
+/// * `Tab` completes the common prefix of the entries, shell style.
+#[derive(Default, Clone)]
+pub struct PathCompletion {
+    extensions: Vec<String>,
+    directories_only: bool,
+}
+
+impl PathCompletion {
+    /// Creates a new path completion source.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Suggests only files with the given extensions (and directories
+    /// to navigate into).
+    pub fn extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extensions = extensions
+            .into_iter()
+            .map(|ext| ext.as_ref().trim_start_matches('.').to_string())
+            .collect();
+        self
+    }
+
+    /// Suggests only directories.
+    pub fn directories_only(mut self) -> Self {
+        self.directories_only = true;
+        self
+    }
+
+    fn is_suggested(&self, path: &Path, is_dir: bool) -> bool {
+        if is_dir {
+            return true;
+        }
+        if self.directories_only {
+            return false;
+        }
+
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .is_some_and(|ext| self.extensions.iter().any(|e| *ext == **e))
+    }
+}
+
+impl Suggest for PathCompletion {
+    type Result = String;
+
+    fn suggest(&self, input: &str) -> Vec<String> {
+        if input == "~" {
+            return vec!["~/".into()];
+        }
+
+        let (dir, prefix) = match input.rfind('/') {
+            Some(i) => input.split_at(i + 1),
+            None => ("", input),
+        };
+        let read_from = match dir {
+            "" => PathBuf::from("."),
+            dir => expand_tilde(dir),
+        };
+
+        let Ok(entries) = fs::read_dir(read_from) else {
+            return vec![];
+        };
+
+        let mut suggestions: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.'))
+                {
+                    return None;
+                }
+
+                let path = entry.path();
+                let is_dir = path.is_dir();
+                self.is_suggested(&path, is_dir).then(|| match is_dir {
+                    true => format!("{dir}{name}/"),
+                    false => format!("{dir}{name}"),
+                })
+            })
+            .collect();
+
+        suggestions.sort();
+        suggestions
+    }
+
+    fn complete(&self, input: &str) -> Option<String> {
+        let suggestions = self.suggest(input);
+        let prefix = common_prefix(&suggestions, false)?;
+
+        (prefix.chars().count() > input.chars().count()).then_some(prefix)
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        match label.starts_with(query) {
+            true => (0..query.chars().count()).collect(),
+            false => vec![],
+        }
+    }
+}
+
+/// A validator requiring the path to exist.
+pub fn exists() -> impl Fn(&String) -> Result<(), String> {
+    |input: &String| match expand_tilde(input).exists() {
+        true => Ok(()),
+        false => Err(MESSAGES.read().unwrap().path_not_found()),
+    }
+}
+
+/// A validator requiring the path to not exist.
+pub fn not_exists() -> impl Fn(&String) -> Result<(), String> {
+    |input: &String| match expand_tilde(input).exists() {
+        true => Err(MESSAGES.read().unwrap().path_already_exists()),
+        false => Ok(()),
+    }
+}
+
+/// Whether files can be created in the directory: a probe file is created
+/// and removed right away.
+fn can_create_in(dir: &Path) -> bool {
+    let probe = dir.join(format!(".cliclack-probe-{}", process::id()));
+    match OpenOptions::new().write(true).create_new(true).open(&probe) {
+        Ok(_) => fs::remove_file(&probe).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// A validator requiring the path to be writable: either an existing
+/// writable file or directory, or a new file in a writable directory.
+///
+/// The access is checked by opening the file for appending (which doesn't
+/// modify it), or by creating a probe file in the directory.
+pub fn writable() -> impl Fn(&String) -> Result<(), String> {
+    |input: &String| {
+        let path = expand_tilde(input);
+        let writable = if path.is_dir() {
+            can_create_in(&path)
+        } else if path.exists() {
+            OpenOptions::new().append(true).open(&path).is_ok()
+        } else {
+            match path.parent() {
+                Some(parent) if parent.as_os_str().is_empty() => can_create_in(Path::new(".")),
+                Some(parent) => can_create_in(parent),
+                None => false,
+            }
+        };
+
+        match writable {
+            true => Ok(()),
+            false => Err(MESSAGES.read().unwrap().path_not_writable()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    /// Creates a directory tree with the given files (`/` ends directories).
+    fn tree(paths: &[&str]) -> (TempDir, String) {
+        let dir = TempDir::new().unwrap();
+        for path in paths {
+            let path = dir.path().join(path);
+            match path.to_string_lossy().ends_with('/') {
+                true => fs::create_dir_all(path).unwrap(),
+                false => fs::write(path, "").unwrap(),
+            }
+        }
+        let root = format!("{}/", dir.path().display());
+        (dir, root)
+    }
+
+    #[test]
+    fn completion() {
+        let (_dir, root) = tree(&[
+            "src/",
+            "src/prompt/",
+            "src/path.rs",
+            "src/prompt.rs",
+            "examples/",
+            "examples/multiselect.rs",
+            "examples/multi_input.rs",
+            "Cargo.toml",
+            "Cargo.lock",
+            ".hidden",
+        ]);
+        let at = |path: &str| format!("{root}{path}");
+        let paths = PathCompletion::new();
+
+        assert_eq!(paths.suggest(&at("sr")), vec![at("src/")]);
+        assert_eq!(
+            paths.suggest(&at("src/p")),
+            vec![at("src/path.rs"), at("src/prompt.rs"), at("src/prompt/")]
+        );
+        assert_eq!(paths.complete(&at("examples/")), Some(at("examples/multi")));
+        assert_eq!(paths.complete(&at("examples/multi")), None);
+
+        // Hidden entries are suggested only for the `.` prefix.
+        assert!(!paths.suggest(&root).contains(&at(".hidden")));
+        assert_eq!(paths.suggest(&at(".")), vec![at(".hidden")]);
+
+        let dirs = PathCompletion::new().directories_only();
+        assert_eq!(dirs.suggest(&at("src/p")), vec![at("src/prompt/")]);
+
+        let toml = PathCompletion::new().extensions([".toml"]);
+        assert_eq!(toml.suggest(&at("Cargo")), vec![at("Cargo.toml")]);
+
+        assert!(paths.suggest(&at("missing/")).is_empty());
+    }
+
+    #[test]
+    fn validators() {
+        let (_dir, root) = tree(&["file", "dir/"]);
+        let at = |path: &str| format!("{root}{path}");
+
+        assert!(exists()(&at("file")).is_ok());
+        assert!(exists()(&at("missing")).is_err());
+        assert!(not_exists()(&at("dir")).is_err());
+        assert!(not_exists()(&at("missing")).is_ok());
+
+        assert!(writable()(&at("file")).is_ok());
+        assert!(writable()(&at("dir")).is_ok());
+        assert!(writable()(&at("dir/new")).is_ok());
+        assert!(writable()(&at("missing/new")).is_err());
+
+        // The probe file is removed.
+        assert_eq!(fs::read_dir(at("dir")).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn tilde() {
+        let Some(home) = std::env::var_os("HOME").map(PathBuf::from) else {
+            return;
+        };
+        assert_eq!(expand_tilde("~/a"), home.join("a"));
+        assert_eq!(expand_tilde("~"), home);
+        assert_eq!(expand_tilde("a/~"), PathBuf::from("a/~"));
+    }
+}
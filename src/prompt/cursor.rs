@@ -1,7 +1,12 @@
 use std::fmt::{Display, Formatter, Result};
+use std::ops::Range;
 
+use console::Style;
 use zeroize::ZeroizeOnDrop;
 
+use super::mask::Mask;
+use crate::highlight::Span;
+
 /// A cursor for editing multiline strings.
 ///
 /// Supports moving the cursor (left, right, up, down), backspace, delete, etc.
@@ -10,6 +15,14 @@ use zeroize::ZeroizeOnDrop;
 pub struct StringCursor {
     value: Vec<char>,
     cursor: usize,
+    #[zeroize(skip)]
+    mask: Option<Mask>,
+    #[zeroize(skip)]
+    highlights: Vec<Span>,
+    #[zeroize(skip)]
+    max_len: Option<usize>,
+    #[zeroize(skip)]
+    ghost_text: String,
 }
 
 /// Returns the indices of the first character of each word in the given string,
@@ -47,19 +60,155 @@ impl StringCursor {
         self.value.is_empty()
     }
 
+    /// Returns the number of lines.
+    pub fn line_count(&self) -> usize {
+        self.value.iter().filter(|c| **c == '\n').count() + 1
+    }
+
+    /// Returns the line (starting from 0) where the cursor is.
+    pub fn cursor_line(&self) -> usize {
+        self.value[..self.cursor]
+            .iter()
+            .filter(|c| **c == '\n')
+            .count()
+    }
+
     /// Returns a character at the current cursor position.
     pub fn current(&self) -> Option<char> {
         self.value.get(self.cursor).copied()
     }
 
+    /// Returns the cursor position as a character index.
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the number of characters.
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Limits the number of characters: insertions beyond the limit are
+    /// ignored, and the current value is truncated.
+    pub(crate) fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = Some(max_len);
+        self.value.truncate(max_len);
+        self.cursor = self.cursor.min(self.value.len());
+    }
+
+    /// Returns the maximum number of characters, if limited.
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Returns the number of characters which can still be inserted.
+    fn remaining(&self) -> usize {
+        self.max_len
+            .map_or(usize::MAX, |max| max.saturating_sub(self.value.len()))
+    }
+
+    /// Sets the text shown after the cursor at the end of the value,
+    /// e.g. the rest of a suggestion.
+    pub(crate) fn set_ghost_text(&mut self, text: String) {
+        self.ghost_text = text;
+    }
+
+    /// Returns the text shown after the cursor at the end of the value.
+    pub fn ghost_text(&self) -> &str {
+        &self.ghost_text
+    }
+
+    /// Sets the mask, so only matching characters are accepted, and literals
+    /// of the mask are inserted automatically.
+    pub(crate) fn set_mask(&mut self, mask: Mask) {
+        let raw = mask.raw(&self.value);
+        self.mask = Some(mask);
+        self.clear();
+        self.extend(&String::from_iter(raw));
+    }
+
+    /// Returns `true` if the cursor has a mask.
+    pub fn is_masked(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Returns `true` if all positions of the mask are filled (always `true`
+    /// without a mask).
+    pub fn is_complete(&self) -> bool {
+        match &self.mask {
+            Some(mask) => mask.is_complete(self.value.len()),
+            None => true,
+        }
+    }
+
+    /// Returns the unfilled part of the mask (e.g. `__:__`), or an empty string
+    /// without a mask.
+    pub fn mask_template(&self) -> String {
+        match &self.mask {
+            Some(mask) => mask.template(self.value.len()),
+            None => String::new(),
+        }
+    }
+
+    /// Returns the value without literals of the mask.
+    pub fn raw_value(&self) -> String {
+        match &self.mask {
+            Some(mask) => String::from_iter(mask.raw(&self.value)),
+            None => self.to_string(),
+        }
+    }
+
+    /// Edits the raw (unmasked) characters: the closure gets the raw characters
+    /// and the raw cursor position, and returns a new raw cursor position.
+    /// The value is then formatted back according to the mask.
+    fn edit_masked(&mut self, edit: impl FnOnce(&mut Vec<char>, usize) -> usize) {
+        let Some(mask) = &self.mask else {
+            return;
+        };
+
+        let mut raw = mask.raw(&self.value);
+        let position = mask.slots_before(self.cursor).min(raw.len());
+        let position = edit(&mut raw, position);
+
+        mask.fit(&mut raw);
+        self.value = mask.format(&raw);
+        self.cursor = mask
+            .slot_position(position.min(raw.len()))
+            .min(self.value.len());
+    }
+
     /// Inserts a character at the current cursor position.
     pub fn insert(&mut self, chr: char) {
+        if let Some(mask) = &self.mask {
+            let mut raw = mask.raw(&self.value);
+            let position = mask.slots_before(self.cursor).min(raw.len());
+            raw.insert(position, chr);
+            if mask.fits(&raw) {
+                self.edit_masked(|raw, position| {
+                    raw.insert(position, chr);
+                    position + 1
+                });
+            }
+            return;
+        }
+
+        if self.remaining() == 0 {
+            return;
+        }
+
         self.value.insert(self.cursor, chr);
         self.cursor += 1;
     }
 
     /// Moves the cursor one position left.
     pub fn move_left(&mut self) {
+        if let Some(mask) = &self.mask {
+            // Jump over literals, but not before the first slot.
+            let position = mask.slots_before(self.cursor).saturating_sub(1);
+            self.cursor = mask.slot_position(position).min(self.value.len());
+            return;
+        }
+
         if self.cursor > 0 {
             self.cursor -= 1;
         }
@@ -67,6 +216,12 @@ impl StringCursor {
 
     /// Moves the cursor one position right.
     pub fn move_right(&mut self) {
+        if let Some(mask) = &self.mask {
+            let position = mask.slots_before(self.cursor + 1);
+            self.cursor = mask.slot_position(position).min(self.value.len());
+            return;
+        }
+
         if self.cursor < self.value.len() {
             self.cursor += 1;
         }
@@ -136,6 +291,16 @@ impl StringCursor {
 
     /// Deletes the character to the left of the cursor.
     pub fn delete_left(&mut self) {
+        if self.mask.is_some() {
+            self.edit_masked(|raw, position| {
+                if position > 0 {
+                    raw.remove(position - 1);
+                }
+                position.saturating_sub(1)
+            });
+            return;
+        }
+
         if self.value.is_empty() {
             return;
         }
@@ -148,6 +313,16 @@ impl StringCursor {
 
     /// Deletes the character to the right of the cursor.
     pub fn delete_right(&mut self) {
+        if self.mask.is_some() {
+            self.edit_masked(|raw, position| {
+                if position < raw.len() {
+                    raw.remove(position);
+                }
+                position
+            });
+            return;
+        }
+
         if self.value.is_empty() {
             return;
         }
@@ -159,6 +334,14 @@ impl StringCursor {
 
     /// Deletes the word to the left of the cursor.
     pub fn delete_word_to_the_left(&mut self) {
+        if self.mask.is_some() {
+            self.edit_masked(|raw, position| {
+                raw.drain(..position);
+                0
+            });
+            return;
+        }
+
         if self.cursor > 0 {
             let jumps = word_jump_indices(&self.value);
             let ix = jumps.binary_search(&self.cursor).unwrap_or_else(|x| x);
@@ -169,6 +352,27 @@ impl StringCursor {
         }
     }
 
+    /// Replaces the range of characters with the string, moving the cursor
+    /// to the end of the inserted text.
+    ///
+    /// With a mask, the whole value is replaced.
+    pub(crate) fn replace(&mut self, range: Range<usize>, string: &str) {
+        if self.mask.is_some() {
+            self.clear();
+            self.extend(string);
+            self.move_end();
+            return;
+        }
+
+        let end = range.end.min(self.value.len());
+        let start = range.start.min(end);
+        let remaining = self.remaining().saturating_add(end - start);
+        let chars: Vec<char> = string.chars().take(remaining).collect();
+
+        self.cursor = start + chars.len();
+        self.value.splice(start..end, chars);
+    }
+
     /// Clears the cursor, removing all characters.
     pub fn clear(&mut self) {
         self.cursor = 0;
@@ -176,8 +380,33 @@ impl StringCursor {
     }
 
     /// Extends the cursor with the contents of a given string.
+    ///
+    /// With a mask, literals and characters not matching the mask are skipped.
+    /// Characters beyond the maximum length are skipped too.
     pub fn extend(&mut self, string: &str) {
-        self.value.extend(string.chars());
+        if let Some(mask) = &self.mask {
+            let mut raw = mask.raw(&self.value);
+            for chr in string.chars() {
+                raw.push(chr);
+                if !mask.fits(&raw) {
+                    raw.pop();
+                }
+            }
+            self.value = mask.format(&raw);
+            self.cursor = self.cursor.min(self.value.len());
+            return;
+        }
+
+        let remaining = self.remaining();
+        self.value.extend(string.chars().take(remaining));
+    }
+
+    /// Extends the cursor like [`StringCursor::extend`], but beyond the
+    /// maximum length, e.g. with a default value given by the program.
+    pub(crate) fn extend_unlimited(&mut self, string: &str) {
+        let max_len = self.max_len.take();
+        self.extend(string);
+        self.max_len = max_len;
     }
 
     /// Splits the cursor into three parts: left, cursor, and right.
@@ -199,6 +428,73 @@ impl StringCursor {
         (left, cursor, right)
     }
 
+    /// Sets the styled spans of the value, see [`StringCursor::split_styled`].
+    pub(crate) fn set_highlights(&mut self, highlights: Vec<Span>) {
+        self.highlights = highlights;
+    }
+
+    /// Applies the highlighted styles to the given range of characters,
+    /// or the default style to characters without highlighting.
+    ///
+    /// Line breaks are never styled, so every line is styled independently.
+    fn styled(&self, range: Range<usize>, default: &Style) -> String {
+        let style_at = |i: usize| {
+            self.highlights
+                .iter()
+                .rev()
+                .find(|span| span.range.contains(&i))
+                .map_or(default, |span| &span.style)
+        };
+
+        let mut result = String::new();
+        let mut start = range.start;
+
+        for i in range.clone() {
+            let end = i + 1;
+            let is_break = self.value[i] == '\n';
+            let is_last = end == range.end
+                || is_break
+                || self.value[end] == '\n'
+                || !std::ptr::eq(style_at(i), style_at(end));
+
+            if is_break {
+                result.push('\n');
+                start = end;
+            } else if is_last {
+                let chunk = String::from_iter(&self.value[start..end]);
+                result.push_str(&style_at(i).apply_to(chunk).to_string());
+                start = end;
+            }
+        }
+
+        result
+    }
+
+    /// Splits the value like [`StringCursor::split`], styling the left and
+    /// right parts with the highlighted spans, or the default style.
+    pub fn split_styled(&self, default: &Style) -> (String, String, String) {
+        if self.highlights.is_empty() {
+            let (left, cursor, right) = self.split();
+            let (left, right) = (default.apply_to(left), default.apply_to(right));
+            return (left.to_string(), cursor, right.to_string());
+        }
+
+        let left = self.styled(0..self.cursor, default);
+        let cursor = match self.current() {
+            Some('\n') | None => String::from(' '),
+            Some(chr) => chr.to_string(),
+        };
+        let right = match self.current() {
+            Some('\n') => self.styled(self.cursor..self.value.len(), default),
+            _ => self.styled(
+                (self.cursor + 1).min(self.value.len())..self.value.len(),
+                default,
+            ),
+        };
+
+        (left, cursor, right)
+    }
+
     /// Returns a mutable iterator over the characters in the cursor.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut char> {
         self.value.iter_mut()
@@ -232,6 +528,10 @@ mod test {
         let mut cursor = StringCursor {
             value: "hello\nworld".chars().collect(),
             cursor: 0,
+            mask: None,
+            highlights: vec![],
+            max_len: None,
+            ghost_text: String::new(),
         };
         assert_cursor!(cursor, 'h');
         assert_content!(cursor, "hello\nworld");
@@ -273,6 +573,10 @@ mod test {
         let mut cursor = StringCursor {
             value: "hello".chars().collect(),
             cursor: 2,
+            mask: None,
+            highlights: vec![],
+            max_len: None,
+            ghost_text: String::new(),
         };
 
         cursor.move_up();
@@ -280,4 +584,82 @@ mod test {
         assert_eq!(cursor.cursor, 2);
         assert_cursor!(cursor, 'l');
     }
+
+    #[test]
+    fn line_position() {
+        let mut cursor = StringCursor::default();
+        cursor.extend("one\ntwo\nthree");
+        assert_eq!(cursor.line_count(), 3);
+        assert_eq!(cursor.cursor_line(), 0);
+        cursor.move_down();
+        cursor.move_down();
+        assert_eq!(cursor.cursor_line(), 2);
+    }
+
+    #[test]
+    fn masked_editing() {
+        let mut cursor = StringCursor::default();
+        cursor.set_mask(Mask::new("##:##"));
+
+        for c in "1a2".chars() {
+            cursor.insert(c);
+        }
+        assert_content!(cursor, "12:");
+        assert_eq!(cursor.mask_template(), "__");
+        cursor.insert('3');
+        cursor.insert('4');
+        cursor.insert('5');
+        assert_content!(cursor, "12:34");
+        assert!(cursor.is_complete());
+        assert_eq!(cursor.raw_value(), "1234");
+
+        cursor.move_left();
+        cursor.move_left();
+        assert_cursor!(cursor, '3');
+        cursor.move_left();
+        assert_cursor!(cursor, '2');
+        cursor.move_right();
+        assert_cursor!(cursor, '3');
+        cursor.delete_left();
+        assert_content!(cursor, "13:4");
+        assert_cursor!(cursor, '3');
+        assert!(!cursor.is_complete());
+
+        cursor.clear();
+        cursor.extend("98:76");
+        assert_content!(cursor, "98:76");
+    }
+
+    #[test]
+    fn max_len() {
+        let mut cursor = StringCursor::default();
+        cursor.set_max_len(3);
+
+        for c in "añb".chars() {
+            cursor.insert(c);
+        }
+        cursor.insert('c');
+        assert_content!(cursor, "añb");
+
+        cursor.delete_left();
+        cursor.extend("xyz");
+        assert_content!(cursor, "añx");
+        assert_eq!(cursor.len(), 3);
+    }
+
+    #[test]
+    fn replace() {
+        let mut cursor = StringCursor::default();
+        cursor.extend("cargo b --rel");
+        cursor.move_home();
+
+        cursor.replace(6..7, "build");
+        assert_content!(cursor, "cargo build --rel");
+        assert_eq!(cursor.position(), 11);
+        assert_cursor!(cursor, ' ');
+
+        cursor.replace(12..17, "--release");
+        assert_content!(cursor, "cargo build --release");
+        assert_eq!(cursor.position(), 21);
+    }
 }
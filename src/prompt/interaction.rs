@@ -1,5 +1,8 @@
 use console::{Key, Term};
 use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
 
 use super::cursor::StringCursor;
 
@@ -31,6 +34,15 @@ fn wrap(text: &str, width: usize) -> String {
     )
 }
 
+/// Reads a key in the background, so the prompt can be re-rendered
+/// while waiting for the user input.
+fn read_key_in_background(term: &Term) -> Receiver<io::Result<Key>> {
+    let (sender, receiver) = mpsc::channel();
+    let term = term.clone();
+    thread::spawn(move || sender.send(term.read_key_raw()));
+    receiver
+}
+
 /// A component that renders itself as a prompt and handles user input.
 ///
 /// Two methods are mandatory to implement:
@@ -50,6 +62,13 @@ pub trait PromptInteraction<T> {
         None
     }
 
+    /// Returns the interval of re-rendering the prompt while waiting for
+    /// the user input, e.g. to animate a spinner or to show results computed
+    /// in the background. No re-rendering by default.
+    fn refresh_interval(&self) -> Option<Duration> {
+        None
+    }
+
     /// Whether features like Alt-Backspace and Alt-ArrowLeft/Right are allowed.
     /// Word editing is disabled for password prompts, for example.
     fn allow_word_editing(&self) -> bool {
@@ -78,6 +97,7 @@ pub trait PromptInteraction<T> {
     fn interact_on_prepared(&mut self, term: &mut Term) -> io::Result<T> {
         let mut state = State::Active;
         let mut prev_frame = String::new();
+        let mut pending_key = None;
 
         loop {
             let frame = self.render(&state);
@@ -98,7 +118,29 @@ pub trait PromptInteraction<T> {
                 _ => {}
             }
 
-            match term.read_key_raw() {
+            let key = match (self.refresh_interval(), pending_key.take()) {
+                (None, None) => term.read_key_raw(),
+                (interval, receiver) => {
+                    let receiver = receiver.unwrap_or_else(|| read_key_in_background(term));
+                    let received = match interval {
+                        Some(interval) => receiver.recv_timeout(interval),
+                        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                    };
+                    match received {
+                        Ok(key) => key,
+                        // Keep waiting for the same key after re-rendering.
+                        Err(RecvTimeoutError::Timeout) => {
+                            pending_key = Some(receiver);
+                            continue;
+                        }
+                        Err(RecvTimeoutError::Disconnected) => {
+                            return Err(io::ErrorKind::BrokenPipe.into())
+                        }
+                    }
+                }
+            };
+
+            match key {
                 Ok(Key::Escape) => {
                     state = State::Cancel;
 
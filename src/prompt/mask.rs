@@ -0,0 +1,179 @@
+/// A position of the mask pattern: either a literal or a class of accepted characters.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Literal(char),
+    Digit,
+    HexDigit,
+    Letter,
+    Alphanumeric,
+}
+
+impl Slot {
+    fn accepts(&self, chr: char) -> bool {
+        match self {
+            Slot::Literal(_) => false,
+            Slot::Digit => chr.is_ascii_digit(),
+            Slot::HexDigit => chr.is_ascii_hexdigit(),
+            Slot::Letter => chr.is_alphabetic(),
+            Slot::Alphanumeric => chr.is_alphanumeric(),
+        }
+    }
+}
+
+/// An input mask, e.g. `(###) ###-####`.
+///
+/// * `#`: a digit.
+/// * `H`: a hexadecimal digit.
+/// * `A`: a letter.
+/// * `*`: a letter or a digit.
+/// * `\`: escapes the next character, making it a literal.
+/// * Any other character is a literal inserted automatically.
+#[derive(Clone, Default)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+impl Mask {
+    /// Parses the mask pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = vec![];
+        let mut chars = pattern.chars();
+
+        while let Some(chr) = chars.next() {
+            slots.push(match chr {
+                '#' => Slot::Digit,
+                'H' => Slot::HexDigit,
+                'A' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                _ => Slot::Literal(chr),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Returns `true` if the position is a literal (or is out of the mask).
+    pub fn is_literal(&self, index: usize) -> bool {
+        !matches!(self.slots.get(index), Some(slot) if !matches!(slot, Slot::Literal(_)))
+    }
+
+    /// The number of non-literal positions.
+    pub fn capacity(&self) -> usize {
+        (0..self.slots.len())
+            .filter(|i| !self.is_literal(*i))
+            .count()
+    }
+
+    /// Returns the position of the `n`-th non-literal slot, or the mask length.
+    pub fn slot_position(&self, n: usize) -> usize {
+        (0..self.slots.len())
+            .filter(|i| !self.is_literal(*i))
+            .nth(n)
+            .unwrap_or(self.slots.len())
+    }
+
+    /// Counts the non-literal slots before the position.
+    pub fn slots_before(&self, index: usize) -> usize {
+        (0..index.min(self.slots.len()))
+            .filter(|i| !self.is_literal(*i))
+            .count()
+    }
+
+    /// Checks if all raw characters are accepted by their slots.
+    pub fn fits(&self, raw: &[char]) -> bool {
+        raw.len() <= self.capacity()
+            && raw
+                .iter()
+                .enumerate()
+                .all(|(n, chr)| self.slots[self.slot_position(n)].accepts(*chr))
+    }
+
+    /// Truncates raw characters not accepted by their slots.
+    pub fn fit(&self, raw: &mut Vec<char>) {
+        let fitting = (0..raw.len())
+            .take_while(|n| self.fits(&raw[..=*n]))
+            .count();
+        raw.truncate(fitting);
+    }
+
+    /// Formats raw characters according to the mask inserting literals.
+    ///
+    /// Literals following the last character are inserted too, so the cursor
+    /// jumps over them.
+    pub fn format(&self, raw: &[char]) -> Vec<char> {
+        let mut formatted = vec![];
+
+        if raw.is_empty() {
+            return formatted;
+        }
+
+        let mut raw = raw.iter();
+        for slot in &self.slots {
+            match slot {
+                Slot::Literal(chr) => formatted.push(*chr),
+                _ => match raw.next() {
+                    Some(chr) => formatted.push(*chr),
+                    None => break,
+                },
+            }
+        }
+
+        formatted
+    }
+
+    /// Extracts raw characters from the formatted value.
+    pub fn raw(&self, formatted: &[char]) -> Vec<char> {
+        formatted
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.is_literal(*i))
+            .map(|(_, chr)| *chr)
+            .collect()
+    }
+
+    /// Checks if all slots are filled in the formatted value of the given length.
+    pub fn is_complete(&self, len: usize) -> bool {
+        self.slots_before(len) == self.capacity()
+    }
+
+    /// Returns the unfilled part of the mask starting from the given position,
+    /// where slots are replaced with `_`.
+    pub fn template(&self, from: usize) -> String {
+        self.slots
+            .iter()
+            .skip(from)
+            .map(|slot| match slot {
+                Slot::Literal(chr) => *chr,
+                _ => '_',
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format_and_raw() {
+        let mask = Mask::new("(###) ###-####");
+        let raw: Vec<char> = "5551234".chars().collect();
+        let formatted = mask.format(&raw);
+
+        assert_eq!(String::from_iter(&formatted), "(555) 123-4");
+        assert_eq!(mask.raw(&formatted), raw);
+        assert_eq!(mask.template(formatted.len()), "___");
+        assert_eq!(mask.capacity(), 10);
+        assert!(!mask.is_complete(formatted.len()));
+        assert!(mask.fits(&raw));
+        assert!(!mask.fits(&['a']));
+    }
+
+    #[test]
+    fn escaped_literals() {
+        let mask = Mask::new(r"\#HH");
+        assert_eq!(String::from_iter(mask.format(&['f', 'f'])), "#ff");
+        assert!(mask.is_complete(3));
+    }
+}
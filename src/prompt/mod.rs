@@ -1,2 +1,3 @@
 pub mod cursor;
 pub mod interaction;
+pub mod mask;
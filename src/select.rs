@@ -6,6 +6,8 @@ use console::Key;
 
 use crate::{
     filter::FilteredView,
+    matcher::Matcher,
+    messages::MESSAGES,
     prompt::{
         cursor::StringCursor,
         interaction::{Event, PromptInteraction, State},
@@ -85,6 +87,20 @@ where
         self
     }
 
+    /// Enables the filter mode ranking the items with the matcher,
+    /// see [`matcher`](crate::matcher). Default: [`JaroWinkler`](crate::matcher::JaroWinkler).
+    ///
+    /// Only the visible items (and some more) are ranked, and more of them
+    /// as the list scrolls down. The default matcher scores the items on
+    /// every keystroke, while [`Subsequence`](crate::matcher::Subsequence)
+    /// and other matchers accepting only the query characters in order
+    /// narrow the matches incrementally, which suits very large lists.
+    pub fn filter_matcher(mut self, matcher: impl Matcher + 'static) -> Self {
+        self.filter.set_matcher(matcher);
+        self.filter.enable();
+        self
+    }
+
     /// The maximum visible number of items.
     ///
     /// If the number of items is greater than the maximum number of rows,
@@ -101,7 +117,7 @@ where
         if self.items.is_empty() {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
-                "No items added to the list",
+                MESSAGES.read().unwrap().no_items_added(),
             ));
         }
         if let Some(initial_value) = &self.initial_value {
@@ -120,7 +136,7 @@ impl<T: Clone> PromptInteraction<T> for Select<T> {
     fn on(&mut self, event: &Event) -> State<T> {
         let Event::Key(key) = event;
 
-        if let Some(state) = self.filter.on(key, &self.items) {
+        if let Some(state) = self.filter.on(key, &self.items, &self.view) {
             if self.filter.items().is_empty() || self.cursor > self.filter.items().len() - 1 {
                 self.cursor = 0;
             }
@@ -179,7 +195,13 @@ impl<T: Clone> PromptInteraction<T> for Select<T> {
             .take(self.view.height)
             .map(|(i, item)| {
                 let item = item.borrow();
-                theme.format_select_item(&state.into(), self.cursor == i, &item.label, &item.hint)
+                theme.format_select_item_matched(
+                    &state.into(),
+                    self.cursor == i,
+                    &item.label,
+                    &item.hint,
+                    &self.filter.positions(&item.label),
+                )
             })
             .collect();
 
@@ -1,4 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, fmt::Display, ops::Range, rc::Rc};
+
+use crate::matcher::{rank, JaroWinkler, Matcher};
 
 /// Provides a list of suggestions for an input.
 ///
@@ -10,12 +12,154 @@ pub trait Suggest {
 
     /// Returns the candidate suggestions for the given `input`.
     fn suggest(&self, input: &str) -> Vec<Self::Result>;
+
+    /// Returns the candidate suggestions for the token of the `input` under
+    /// the `cursor`, along with the range of the token replaced by
+    /// an accepted suggestion. The cursor and the range are character
+    /// (not byte) indices.
+    ///
+    /// Suggests for the whole `input` by default. Override it to complete
+    /// a command line word by word: e.g. a subcommand, then a flag, then a file.
+    ///
+    /// ```
+    /// use std::ops::Range;
+    /// use cliclack::Suggest;
+    ///
+    /// struct Command;
+    ///
+    /// impl Suggest for Command {
+    ///     type Result = String;
+    ///
+    ///     fn suggest(&self, input: &str) -> Vec<String> {
+    ///         self.suggest_at(input, input.chars().count()).1
+    ///     }
+    ///
+    ///     fn suggest_at(&self, input: &str, cursor: usize) -> (Range<usize>, Vec<String>) {
+    ///         let chars: Vec<char> = input.chars().collect();
+    ///         let start = chars[..cursor]
+    ///             .iter()
+    ///             .rposition(|c| c.is_whitespace())
+    ///             .map_or(0, |i| i + 1);
+    ///         let word: String = chars[start..cursor].iter().collect();
+    ///
+    ///         // The first word is a subcommand, others are flags.
+    ///         let words = match start {
+    ///             0 => vec!["build", "check", "test"],
+    ///             _ => vec!["--release", "--verbose"],
+    ///         };
+    ///         let suggestions = words
+    ///             .into_iter()
+    ///             .filter(|w| w.starts_with(&word))
+    ///             .map(String::from)
+    ///             .collect();
+    ///
+    ///         (start..cursor, suggestions)
+    ///     }
+    /// }
+    ///
+    /// let (range, suggestions) = Command.suggest_at("build --r", 9);
+    /// assert_eq!(range, 6..9);
+    /// assert_eq!(suggestions, vec!["--release"]);
+    /// ```
+    fn suggest_at(&self, input: &str, _cursor: usize) -> (Range<usize>, Vec<Self::Result>) {
+        (0..input.chars().count(), self.suggest(input))
+    }
+
+    /// Completes the `input` (or the token under the cursor, see
+    /// [`Suggest::suggest_at`]) on `Tab` when no suggestion is highlighted,
+    /// e.g. with the common prefix of the suggestions, shell style.
+    ///
+    /// Returns `None` (no completion) by default.
+    fn complete(&self, _input: &str) -> Option<String> {
+        None
+    }
+
+    /// Returns the character indices of the suggestion `label` matching
+    /// the `query` (the input, or its token, see [`Suggest::suggest_at`]),
+    /// which are highlighted in the list of suggestions.
+    ///
+    /// Returns no positions (no highlighting) by default.
+    fn positions(&self, _label: &str, _query: &str) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// An autocomplete suggestion with a hint, an inserted text, and a typed value,
+/// see [`Input::autocomplete_items`](crate::Input::autocomplete_items).
+///
+/// ```
+/// use cliclack::Suggestion;
+///
+/// let suggestion = Suggestion::new("serde")
+///     .hint("Serialization framework — v1.0.219")
+///     .insert("serde = \"1\"")
+///     .value(("serde", 1));
+/// ```
+#[derive(Clone)]
+pub struct Suggestion<T> {
+    pub(crate) label: String,
+    pub(crate) hint: String,
+    pub(crate) insert: Option<String>,
+    pub(crate) value: Option<T>,
+}
+
+impl<T> Suggestion<T> {
+    /// Creates a new suggestion displayed with the label.
+    pub fn new(label: impl Display) -> Self {
+        Self {
+            label: label.to_string(),
+            hint: String::new(),
+            insert: None,
+            value: None,
+        }
+    }
+
+    /// Sets a hint displayed next to the label, which is not inserted into the input.
+    pub fn hint(mut self, hint: impl Display) -> Self {
+        self.hint = hint.to_string();
+        self
+    }
+
+    /// Sets the text inserted into the input when the suggestion is accepted.
+    /// The label is inserted by default.
+    pub fn insert(mut self, text: impl Display) -> Self {
+        self.insert = Some(text.to_string());
+        self
+    }
+
+    /// Sets the value returned by the prompt if the suggestion is accepted
+    /// (and the inserted text is not edited afterwards), instead of parsing
+    /// the input text.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+/// Turns a vector of suggestions into a fuzzy searchable source.
+///
+/// Suggestions are matched by their labels with [`JaroWinkler`] and returned
+/// cloned. See [`Matched`](crate::matcher::Matched) for other matchers.
+impl<T: Clone> Suggest for Vec<Suggestion<T>> {
+    type Result = Suggestion<T>;
+
+    fn suggest(&self, input: &str) -> Vec<Suggestion<T>> {
+        let labeled_items = self.iter().map(|s| (s.label.as_str(), s.clone()));
+        rank(labeled_items, input, &JaroWinkler::default())
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        JaroWinkler::default().positions(label, query)
+    }
 }
 
 /// Turns a vector of shared displayable elements into a fuzzy searchable source.
 ///
 /// Labels are taken from `item.as_ref()`. Suggestions keep
 /// shared ownership by returning `Rc<RefCell<T>>` values.
+///
+/// All labels are scored on every query. For very large lists, see
+/// [`Matched`](crate::matcher::Matched) indexing them once.
 impl<T> Suggest for Vec<Rc<RefCell<T>>>
 where
     T: AsRef<str>,
@@ -25,8 +169,12 @@ where
     fn suggest(&self, input: &str) -> Vec<Rc<RefCell<T>>> {
         let labeled_items = self
             .iter()
-            .map(|i| (i.borrow().as_ref().to_lowercase(), Rc::clone(i)));
-        fuzzy(labeled_items, &input.to_lowercase())
+            .map(|i| (i.borrow().as_ref().to_string(), Rc::clone(i)));
+        rank(labeled_items, input, &JaroWinkler::default())
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        JaroWinkler::default().positions(label, query)
     }
 }
 
@@ -38,8 +186,12 @@ impl Suggest for Vec<String> {
     type Result = String;
 
     fn suggest(&self, input: &str) -> Vec<String> {
-        let labeled_items = self.iter().map(|s| (s.to_lowercase(), s.clone()));
-        fuzzy(labeled_items, &input.to_lowercase())
+        let labeled_items = self.iter().map(|s| (s.as_str(), s.clone()));
+        rank(labeled_items, input, &JaroWinkler::default())
+    }
+
+    fn positions(&self, label: &str, query: &str) -> Vec<usize> {
+        JaroWinkler::default().positions(label, query)
     }
 }
 
@@ -57,32 +209,23 @@ where
     }
 }
 
-/// Ranks items by fuzzy similarity between their labels and the input.
-///
-/// Empty or whitespace input keeps all items in original order.
-///
-/// Otherwise:
-/// - Uses Jaro-Winkler similarity algorithm ([`strsim::jaro_winkler`]).
-/// - Adds a bonus if all whitespace-separated input words appear in the label.
-/// - Filters weak matches (score <= 0.6) and sorts by score descending.
-///
-/// Labels are used only for scoring. The output contains the associated items.
-fn fuzzy<T>(items: impl Iterator<Item = (String, T)>, input: &str) -> Vec<T> {
-    if input.trim().is_empty() {
-        return items.map(|(_, item)| item).collect();
-    }
-
-    let filter_words: Vec<_> = input.split_whitespace().collect();
+/// Returns the longest common prefix of the strings, in the case of
+/// the first string if the case is ignored.
+pub(crate) fn common_prefix(strings: &[String], ignore_case: bool) -> Option<String> {
+    let (first, rest) = strings.split_first()?;
+    let eq = |a: char, b: char| match ignore_case {
+        true => a.to_lowercase().eq(b.to_lowercase()),
+        false => a == b,
+    };
 
-    let mut scored: Vec<_> = items
-        .map(|(label, item)| {
-            let similarity = strsim::jaro_winkler(&label, input);
-            let bonus = filter_words.iter().all(|word| label.contains(*word)) as usize as f64;
-            (similarity + bonus, item)
-        })
-        .filter(|(score, _)| *score > 0.6)
-        .collect();
+    let len = rest.iter().fold(first.chars().count(), |len, s| {
+        first
+            .chars()
+            .zip(s.chars())
+            .take(len)
+            .take_while(|&(a, b)| eq(a, b))
+            .count()
+    });
 
-    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
-    scored.into_iter().map(|(_, item)| item).collect()
+    Some(first.chars().take(len).collect())
 }
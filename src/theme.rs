@@ -4,6 +4,7 @@ use console::{style, Emoji, Style};
 use once_cell::sync::Lazy;
 use textwrap::core::display_width;
 
+use crate::messages::MESSAGES;
 use crate::prompt::{cursor::StringCursor, interaction::State};
 
 const S_STEP_ACTIVE: Emoji = Emoji("◆", "*");
@@ -43,6 +44,41 @@ pub fn termwrap(text: &str, padding: u16) -> String {
         .join("\n")
 }
 
+/// Styles the runs of the label characters at `matches` (sorted character
+/// indices) with `match_style`, and all runs with `label_style`, so the label
+/// style is restored after the reset ending every matched run.
+fn highlight_runs(
+    label: &str,
+    matches: &[usize],
+    match_style: &Style,
+    label_style: &Style,
+) -> String {
+    if matches.is_empty() {
+        return label.to_string();
+    }
+
+    let mut runs: Vec<(bool, String)> = Vec::new();
+    let mut next = 0;
+
+    for (i, chr) in label.chars().enumerate() {
+        while next < matches.len() && matches[next] < i {
+            next += 1;
+        }
+        let is_match = next < matches.len() && matches[next] == i;
+        match runs.last_mut() {
+            Some((matched, run)) if *matched == is_match => run.push(chr),
+            _ => runs.push((is_match, chr.to_string())),
+        }
+    }
+
+    runs.into_iter()
+        .map(|(matched, run)| match matched {
+            true => label_style.apply_to(match_style.apply_to(run)).to_string(),
+            false => label_style.apply_to(run).to_string(),
+        })
+        .collect()
+}
+
 /// The state of the prompt rendering.
 pub enum ThemeState {
     /// The prompt is active.
@@ -225,15 +261,43 @@ pub trait Theme {
         }
     }
 
+    /// Returns the console style of the ghost text: an inline suggestion
+    /// drawn after the cursor.
+    fn ghost_text_style(&self) -> Style {
+        Style::new().dim()
+    }
+
+    /// Returns the console style of the line numbers in the gutter of the
+    /// multiline input (see [`Input::line_numbers`](crate::Input::line_numbers)).
+    fn line_number_style(&self, _state: &ThemeState) -> Style {
+        Style::new().dim()
+    }
+
     /// Highlights the cursor character in the input text formatting the whole
     /// string with the given style.
+    ///
+    /// The unfilled part of the input mask (if any) is drawn in the placeholder style,
+    /// and the ghost text (if any) is drawn in the ghost text style.
+    /// Highlighted spans of the input (see [`Input::highlight`](crate::Input::highlight))
+    /// take precedence over the given style.
     fn cursor_with_style(&self, cursor: &StringCursor, new_style: &Style) -> String {
-        let (left, cursor, right) = cursor.split();
+        let mut template = cursor.mask_template();
+        let mut ghost = cursor.ghost_text().to_string();
+        let (left, mut current, right) = cursor.split_styled(new_style);
+
+        if cursor.current().is_none() && !template.is_empty() {
+            current = template.remove(0).to_string();
+        } else if cursor.current().is_none() && !ghost.is_empty() {
+            current = ghost.remove(0).to_string();
+        }
+
         format!(
-            "{left}{cursor}{right}",
-            left = new_style.apply_to(left),
-            cursor = style(cursor).reverse(),
-            right = new_style.apply_to(right),
+            "{left}{cursor}{right}{template}{ghost}",
+            cursor = style(current).reverse(),
+            template = self
+                .placeholder_style(&ThemeState::Active)
+                .apply_to(template),
+            ghost = self.ghost_text_style().apply_to(ghost),
         )
     }
 
@@ -304,7 +368,10 @@ pub trait Theme {
             "{}\n", // '\n' vanishes by style applying, thus exclude it from styling
             self.bar_color(state).apply_to(match state {
                 ThemeState::Active => format!("{S_BAR_END}  {message}"),
-                ThemeState::Cancel => format!("{S_BAR_END}  Operation cancelled."),
+                ThemeState::Cancel => format!(
+                    "{S_BAR_END}  {cancelled}",
+                    cancelled = MESSAGES.read().unwrap().operation_cancelled()
+                ),
                 ThemeState::Submit => format!("{S_BAR}"),
                 ThemeState::Error(err) => format!("{S_BAR_END}  {err}"),
             })
@@ -316,28 +383,81 @@ pub trait Theme {
         self.format_footer_with_message(state, message)
     }
 
+    /// Returns the console style of the character counter of the input with
+    /// a limited length, colored like an error when the length is close to
+    /// the limit.
+    fn counter_style(&self, state: &ThemeState, len: usize, max_len: usize) -> Style {
+        match state {
+            ThemeState::Active if len * 10 >= max_len * 9 => {
+                self.bar_color(&ThemeState::Error(String::new()))
+            }
+            _ => Style::new(),
+        }
+    }
+
+    /// Formats the character counter of the input with a limited length
+    /// (like `42/63`), see [`Theme::counter_style`].
+    fn format_counter(&self, state: &ThemeState, len: usize, max_len: usize) -> String {
+        self.counter_style(state, len, max_len)
+            .apply_to(format!("{len}/{max_len}"))
+            .to_string()
+    }
+
     /// Formats the input cursor with the given style adding frame bars around.
     ///
     /// It hides the cursor when the input is not active.
     fn format_input(&self, state: &ThemeState, cursor: &StringCursor) -> String {
+        self.format_input_window(state, cursor, 0, usize::MAX, false)
+    }
+
+    /// Formats a window of the input lines: `height` lines starting from the
+    /// line `start`, optionally with line numbers in the gutter.
+    ///
+    /// All lines without numbers are shown when the input is not active.
+    fn format_input_window(
+        &self,
+        state: &ThemeState,
+        cursor: &StringCursor,
+        start: usize,
+        height: usize,
+        line_numbers: bool,
+    ) -> String {
         let new_style = &self.input_style(state);
 
-        let input = &mut match state {
-            ThemeState::Active | ThemeState::Error(_) => self.cursor_with_style(cursor, new_style),
-            _ => cursor.to_string(),
+        let (input, start, height, line_numbers) = &mut match state {
+            ThemeState::Active | ThemeState::Error(_) => (
+                self.cursor_with_style(cursor, new_style),
+                start,
+                height,
+                line_numbers,
+            ),
+            _ => (cursor.to_string(), 0, usize::MAX, false),
         };
         if input.ends_with('\n') {
             input.push('\n');
         }
 
-        input.lines().fold(String::new(), |acc, line| {
-            format!(
-                "{}{}  {}\n",
-                acc,
-                self.bar_color(state).apply_to(S_BAR),
-                new_style.apply_to(line)
-            )
-        })
+        let width = input.lines().count().to_string().len();
+
+        input.lines().enumerate().skip(*start).take(*height).fold(
+            String::new(),
+            |acc, (i, line)| {
+                let gutter = match *line_numbers {
+                    true => self
+                        .line_number_style(state)
+                        .apply_to(format!("{:>width$} ", i + 1))
+                        .to_string(),
+                    false => String::new(),
+                };
+                format!(
+                    "{}{}  {}{}\n",
+                    acc,
+                    self.bar_color(state).apply_to(S_BAR),
+                    gutter,
+                    new_style.apply_to(line)
+                )
+            },
+        )
     }
 
     /// Formats the input cursor with the dimmed style of placeholder.
@@ -364,6 +484,31 @@ pub trait Theme {
         })
     }
 
+    /// Returns the console style of the label characters matched by the
+    /// filter or the autocompletion, underlined in the active and error states.
+    fn match_style(&self, state: &ThemeState) -> Style {
+        match state {
+            ThemeState::Active | ThemeState::Error(_) => Style::new().underlined(),
+            _ => Style::new(),
+        }
+    }
+
+    /// Highlights the label characters matched by the filter or the
+    /// autocompletion: `matches` are sorted character indices of the label.
+    ///
+    /// Each run of the matched characters is styled with [`Theme::match_style`].
+    /// The result is styled further as an item label with `label_style`, which
+    /// is restored after every run.
+    fn highlight_matches(
+        &self,
+        state: &ThemeState,
+        label: &str,
+        matches: &[usize],
+        label_style: &Style,
+    ) -> String {
+        highlight_runs(label, matches, &self.match_style(state), label_style)
+    }
+
     /// Returns the radio item without frame bars around the item.
     ///
     /// The radio item is used in the selection list and in the confirmation prompt.
@@ -424,6 +569,26 @@ pub trait Theme {
         )
     }
 
+    /// Returns the full select list item formatting with the label characters
+    /// at `matches` (see [`Theme::highlight_matches`]) matched by the filter.
+    ///
+    /// By default, highlights the matches and calls [`Theme::format_select_item`].
+    fn format_select_item_matched(
+        &self,
+        state: &ThemeState,
+        selected: bool,
+        label: &str,
+        hint: &str,
+        matches: &[usize],
+    ) -> String {
+        let label_style = match selected {
+            true => self.input_style(state),
+            false => self.placeholder_style(state),
+        };
+        let label = self.highlight_matches(state, label, matches, &label_style);
+        self.format_select_item(state, selected, &label, hint)
+    }
+
     /// Returns the checkbox item without frame bars around the item.
     ///
     /// Hides the item if not selected on the submit and cancel states.
@@ -482,6 +647,25 @@ pub trait Theme {
         )
     }
 
+    /// Returns the full multiselect list item formatting with the label
+    /// characters at `matches` (see [`Theme::highlight_matches`]) matched
+    /// by the filter.
+    ///
+    /// By default, highlights the matches and calls [`Theme::format_multiselect_item`].
+    fn format_multiselect_item_matched(
+        &self,
+        state: &ThemeState,
+        selected: bool, // when item is selected/checked
+        active: bool,   // when cursors highlights item
+        label: &str,
+        hint: &str,
+        matches: &[usize],
+    ) -> String {
+        let label_style = self.checkbox_style(state, selected, active);
+        let label = self.highlight_matches(state, label, matches, &label_style);
+        self.format_multiselect_item(state, selected, active, &label, hint)
+    }
+
     /// Returns the simple item without checkbox, hints, and frame bars around the item.
     fn simple_item(
         &self,
@@ -502,10 +686,144 @@ pub trait Theme {
         )
     }
 
+    /// Returns the full autocomplete suggestion formatting: the item with
+    /// the hint of the suggestion (see [`Suggestion::hint`](crate::Suggestion::hint)),
+    /// and the label characters at `matches` (see [`Theme::highlight_matches`])
+    /// matched by the input.
+    ///
+    /// By default, highlights the matches and appends the hint to
+    /// [`Theme::format_autocomplete_item`].
+    fn format_autocomplete_suggestion(
+        &self,
+        state: &ThemeState,
+        active: bool,
+        label: &str,
+        hint: &str,
+        matches: &[usize],
+    ) -> String {
+        let label_style = self.checkbox_style(&ThemeState::Submit, false, active);
+        let label = self.highlight_matches(state, label, matches, &label_style);
+        let item = self.format_autocomplete_item(state, active, &label);
+        if hint.is_empty() {
+            return item;
+        }
+
+        format!(
+            "{item}  {hint}\n",
+            item = item.trim_end_matches('\n'),
+            hint = self.placeholder_style(&ThemeState::Active).apply_to(hint)
+        )
+    }
+
+    /// Returns the row under the autocomplete suggestions telling how many
+    /// of them are not visible (like `+12 more`).
+    fn format_autocomplete_more(&self, _state: &ThemeState, hidden: usize) -> String {
+        format!(
+            "{bar}  {more}\n",
+            bar = self.bar_color(&ThemeState::Submit).apply_to(S_BAR),
+            more = style(MESSAGES.read().unwrap().more_items(hidden)).dim(),
+        )
+    }
+
+    /// Returns the row shown while autocomplete suggestions are computed
+    /// in the background, with a spinner animated by the `frame` counter.
+    fn format_autocomplete_pending(&self, _state: &ThemeState, frame: usize) -> String {
+        let spinner = self.spinner_chars().chars().cycle().nth(frame);
+        format!(
+            "{bar}  {spinner} {message}\n",
+            bar = self.bar_color(&ThemeState::Submit).apply_to(S_BAR),
+            spinner = style(spinner.unwrap_or(' ')).magenta(),
+            message = style(MESSAGES.read().unwrap().suggestions_pending()).dim(),
+        )
+    }
+
+    /// Returns a day cell of the calendar (2 characters wide).
+    ///
+    /// The selected day is reversed in the bar color of the state,
+    /// or of the cancel state if the day is disabled.
+    fn format_calendar_day(
+        &self,
+        state: &ThemeState,
+        day: u32,
+        selected: bool,
+        enabled: bool,
+    ) -> String {
+        let day_style = match (selected, enabled) {
+            (true, true) => self.bar_color(state).reverse(),
+            (true, false) => self.bar_color(&ThemeState::Cancel).reverse(),
+            (false, false) => Style::new().dim().strikethrough(),
+            (false, true) => Style::new(),
+        };
+        day_style.apply_to(format!("{day:>2}")).to_string()
+    }
+
+    /// Returns the month calendar: the title (month and year), the row of
+    /// weekday names, and the weeks of day cells (empty cells are padding).
+    ///
+    /// Hidden at the submit and cancel states.
+    fn format_calendar(
+        &self,
+        state: &ThemeState,
+        title: &str,
+        weekdays: &[String],
+        weeks: &[Vec<String>],
+    ) -> String {
+        if matches!(state, ThemeState::Submit | ThemeState::Cancel) {
+            return String::new();
+        }
+
+        let bar = self.bar_color(state).apply_to(S_BAR);
+        let weekdays = weekdays
+            .iter()
+            .map(|name| format!("{name:2}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut calendar = format!(
+            "{bar}  {title}\n{bar}  {weekdays}\n",
+            title = style(title).bold(),
+            weekdays = style(weekdays).dim(),
+        );
+        for week in weeks {
+            let days = week
+                .iter()
+                .map(|day| if day.is_empty() { "  " } else { day })
+                .collect::<Vec<_>>()
+                .join(" ");
+            calendar.push_str(&format!("{bar}  {days}\n"));
+        }
+        calendar
+    }
+
+    /// Returns the time segments (hours, minutes, seconds) separated by `:`,
+    /// highlighting the active segment.
+    fn format_time(&self, state: &ThemeState, segments: &[String], active: usize) -> String {
+        let input_style = self.input_style(state);
+        let time = segments
+            .iter()
+            .enumerate()
+            .map(|(i, segment)| match state {
+                ThemeState::Active | ThemeState::Error(_) if i == active => self
+                    .bar_color(state)
+                    .reverse()
+                    .apply_to(segment)
+                    .to_string(),
+                _ => input_style.apply_to(segment).to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(&input_style.apply_to(":").to_string());
+
+        format!(
+            "{bar}  {time}\n",
+            bar = self.bar_color(state).apply_to(S_BAR),
+        )
+    }
+
     /// Returns the full confirmation prompt rendering.
     fn format_confirm(&self, state: &ThemeState, confirm: bool) -> String {
-        let yes = self.radio_item(state, confirm, "Yes", "");
-        let no = self.radio_item(state, !confirm, "No", "");
+        let messages = MESSAGES.read().unwrap();
+        let yes = self.radio_item(state, confirm, &messages.yes(), "");
+        let no = self.radio_item(state, !confirm, &messages.no(), "");
 
         let inactive_style = &self.placeholder_style(state);
         let divider = match state {
@@ -624,7 +942,11 @@ pub trait Theme {
         message: &str,
     ) -> String {
         // If the prompt is visually empty, clean it up.
-        let prompt = (display_width(prompt) == 0).then(|| "").unwrap_or(prompt);
+        let prompt = if display_width(prompt) == 0 {
+            ""
+        } else {
+            prompt
+        };
 
         // Wrap text to fit terminal width, accounting for box border overhead.
         let prompt = termwrap(prompt, 7);
@@ -822,4 +1144,53 @@ mod tests {
         assert!(!rendered.contains('◇'));
         assert!(rendered.starts_with('├'));
     }
+
+    #[test]
+    fn format_autocomplete_suggestion() {
+        // A theme overriding only the item still gets the hint appended.
+        struct Plain;
+        impl Theme for Plain {
+            fn format_autocomplete_item(&self, _: &ThemeState, _: bool, label: &str) -> String {
+                format!("> {label}\n")
+            }
+        }
+
+        let rendered =
+            Plain.format_autocomplete_suggestion(&ThemeState::Active, false, "a", "b", &[]);
+        assert_eq!(strip_ansi_codes(&rendered), "> a  b\n");
+
+        let rendered =
+            Plain.format_autocomplete_suggestion(&ThemeState::Active, false, "a", "", &[]);
+        assert_eq!(rendered, "> a\n");
+    }
+
+    #[test]
+    fn highlight_matches() {
+        let underlined = Style::new().underlined().force_styling(true);
+        let dim = Style::new().dim().force_styling(true);
+        let matched = |run| dim.apply_to(underlined.apply_to(run)).to_string();
+        let unmatched = |run| dim.apply_to(run).to_string();
+
+        assert_eq!(
+            highlight_runs("rustup", &[0, 1, 4], &underlined, &dim),
+            [matched("ru"), unmatched("st"), matched("u"), unmatched("p")].concat()
+        );
+
+        // Out-of-range matches are ignored.
+        assert_eq!(
+            highlight_runs("go", &[1, 7], &underlined, &dim),
+            [unmatched("g"), matched("o")].concat()
+        );
+        assert_eq!(highlight_runs("go", &[], &underlined, &dim), "go");
+
+        // Nothing is highlighted after submitting.
+        let state = &ThemeState::Active;
+        assert_eq!(ClackTheme.match_style(state), Style::new().underlined());
+        assert_eq!(ClackTheme.match_style(&ThemeState::Submit), Style::new());
+
+        // The highlighted label is styled further as an item.
+        let item = ClackTheme.format_select_item_matched(state, false, "rustup", "", &[0, 1]);
+        let plain = ClackTheme.format_select_item(state, false, "rustup", "");
+        assert_eq!(strip_ansi_codes(&item), strip_ansi_codes(&plain));
+    }
 }
@@ -0,0 +1,233 @@
+use std::fmt::Display;
+use std::io;
+
+use chrono::{Local, NaiveTime, Timelike};
+use console::Key;
+
+use crate::{
+    messages::MESSAGES,
+    prompt::interaction::{Event, PromptInteraction, State},
+    theme::THEME,
+};
+
+/// The upper bounds of the hour, minute and second segments.
+const LIMITS: [u32; 3] = [24, 60, 60];
+
+/// A prompt that picks a time of day in the `HH:MM` or `HH:MM:SS` format
+/// (requires the `chrono` feature).
+///
+/// * `Left`/`Right` to switch the segment.
+/// * `Up`/`Down` to change the value of the segment.
+/// * Type digits to enter the value of the segment.
+/// * `Enter` to submit.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use cliclack::TimePicker;
+///
+/// # fn test() -> std::io::Result<()> {
+/// let time: NaiveTime = TimePicker::new("Start at")
+///     .initial_value(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// # test().ok(); // Ignoring I/O runtime errors.
+/// ```
+#[derive(Default)]
+pub struct TimePicker {
+    prompt: String,
+    segments: [u32; 3],
+    active: usize,
+    typed: bool,
+    with_seconds: bool,
+    initial_value: Option<NaiveTime>,
+}
+
+impl TimePicker {
+    /// Creates a new time prompt.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the initial time. Default: the current time.
+    pub fn initial_value(mut self, time: NaiveTime) -> Self {
+        self.initial_value = Some(time);
+        self
+    }
+
+    /// Enables the seconds segment (`HH:MM:SS`).
+    pub fn with_seconds(mut self) -> Self {
+        self.with_seconds = true;
+        self
+    }
+
+    /// Starts the prompt interaction.
+    pub fn interact(&mut self) -> io::Result<NaiveTime> {
+        let time = self.initial_value.unwrap_or_else(|| {
+            let now = Local::now().time();
+            NaiveTime::from_hms_opt(now.hour(), now.minute(), 0).unwrap_or_default()
+        });
+
+        self.segments = [time.hour(), time.minute(), time.second()];
+        if !self.with_seconds {
+            self.segments[2] = 0;
+        }
+
+        <Self as PromptInteraction<NaiveTime>>::interact(self)
+    }
+
+    fn segment_count(&self) -> usize {
+        if self.with_seconds {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn time(&self) -> NaiveTime {
+        let [h, m, s] = self.segments;
+        NaiveTime::from_hms_opt(h, m, s).unwrap_or_default()
+    }
+
+    fn switch_segment(&mut self, next: bool) {
+        self.typed = false;
+        self.active = match next {
+            true => (self.active + 1).min(self.segment_count() - 1),
+            false => self.active.saturating_sub(1),
+        };
+    }
+}
+
+impl PromptInteraction<NaiveTime> for TimePicker {
+    fn on(&mut self, event: &Event) -> State<NaiveTime> {
+        let Event::Key(key) = event;
+        let limit = LIMITS[self.active];
+        let value = &mut self.segments[self.active];
+
+        match key {
+            Key::ArrowLeft => self.switch_segment(false),
+            Key::ArrowRight | Key::Tab | Key::Char(':') => self.switch_segment(true),
+            Key::ArrowUp => {
+                *value = (*value + 1) % limit;
+                self.typed = false;
+            }
+            Key::ArrowDown => {
+                *value = (*value + limit - 1) % limit;
+                self.typed = false;
+            }
+            Key::Char(chr) if chr.is_ascii_digit() => {
+                let digit = chr.to_digit(10).unwrap_or_default();
+                let typed = *value * 10 + digit;
+
+                if self.typed && typed < limit {
+                    *value = typed;
+                    self.switch_segment(true);
+                } else {
+                    *value = digit;
+                    self.typed = true;
+
+                    // No second digit is possible, e.g. `3` for hours.
+                    if digit * 10 >= limit {
+                        self.switch_segment(true);
+                    }
+                }
+            }
+            Key::Backspace => *value /= 10,
+            Key::Enter => return State::Submit(self.time()),
+            _ => {}
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<NaiveTime>) -> String {
+        let theme = THEME.read().unwrap();
+        let messages = MESSAGES.read().unwrap();
+
+        let segments: Vec<String> = self.segments[..self.segment_count()]
+            .iter()
+            .map(|value| format!("{value:02}"))
+            .collect();
+        let [h, m, s] = self.segments;
+        let hint = format!(
+            "= {}",
+            messages.time_of_day(h, m, self.with_seconds.then_some(s))
+        );
+
+        let line1 = theme.format_header(&state.into(), &self.prompt);
+        let line2 = theme.format_time(&state.into(), &segments, self.active);
+        let line3 = theme.format_footer_with_message(&state.into(), &hint);
+
+        line1 + &line2 + &line3
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn at(h: u32, m: u32, s: u32) -> TimePicker {
+        let mut picker = TimePicker::new("Time");
+        picker.segments = [h, m, s];
+        picker
+    }
+
+    fn press(picker: &mut TimePicker, keys: &[Key]) -> State<NaiveTime> {
+        let mut state = State::Active;
+        for key in keys {
+            state = picker.on(&Event::Key(key.clone()));
+        }
+        state
+    }
+
+    fn time(h: u32, m: u32, s: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, s).unwrap()
+    }
+
+    #[test]
+    fn arrows() {
+        // Values wrap around at the segment limits.
+        let mut picker = at(23, 0, 0);
+        press(
+            &mut picker,
+            &[Key::ArrowUp, Key::ArrowRight, Key::ArrowDown],
+        );
+        assert_eq!(picker.time(), time(0, 59, 0));
+
+        // Without seconds, the minutes segment is the last one.
+        press(&mut picker, &[Key::ArrowRight, Key::ArrowUp]);
+        assert_eq!(picker.time(), time(0, 0, 0));
+        press(
+            &mut picker,
+            &[Key::ArrowLeft, Key::ArrowLeft, Key::ArrowDown],
+        );
+        assert_eq!(picker.time(), time(23, 0, 0));
+    }
+
+    #[test]
+    fn typing() {
+        let mut picker = at(12, 0, 0).with_seconds();
+        let keys = "093015".chars().map(Key::Char).collect::<Vec<_>>();
+        press(&mut picker, &keys);
+        assert_eq!(picker.time(), time(9, 30, 15));
+
+        // A digit which can't be followed by another one moves to the next segment.
+        let mut picker = at(12, 0, 0);
+        press(&mut picker, &[Key::Char('7'), Key::Char('5')]);
+        assert_eq!(picker.time(), time(7, 5, 0));
+
+        // An out-of-range second digit starts the segment over.
+        let mut picker = at(12, 0, 0);
+        press(&mut picker, &[Key::Char('2'), Key::Char('5')]);
+        assert_eq!(picker.time(), time(5, 0, 0));
+
+        let mut picker = at(12, 34, 0);
+        let state = press(&mut picker, &[Key::ArrowRight, Key::Backspace, Key::Enter]);
+        assert!(matches!(state, State::Submit(t) if t == time(12, 3, 0)));
+    }
+}